@@ -0,0 +1,188 @@
+// https://adventofcode.com/2024/day/1
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::phase;
+use utils::sort;
+use utils::validate::{columns, Input, Problem};
+
+pub fn get_lists(path: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn std::error::Error>> {
+    let mut list_a = Vec::new();
+    let mut list_b = Vec::new();
+
+    let data = get_csv_data::<u32>(path, false)?;
+
+    for result in data {
+        if result.len() >= 2 {
+            list_a.push(result[0]);
+            list_b.push(result[1]);
+        } else {
+            return Err("CSV row has fewer than 2 columns".into());
+        }
+    }
+
+    return Ok((list_a, list_b));
+}
+
+pub fn minimum_distance(list_a: &mut Vec<u32>, list_b: &mut Vec<u32>) -> u32 {
+    let mut dist: u32 = 0;
+    *list_a = sort(list_a);
+    *list_b = sort(list_b);
+
+    for i in 0..list_a.len() {
+        let mut sorted: Vec<u32> = vec![list_a[i], list_b[i]];
+        sorted = sort(&mut sorted);
+        dist += sorted[1] - sorted[0];
+    }
+
+    return dist;
+}
+
+pub fn similarity_score(list_a: &[u32], list_b: &[u32]) -> u32 {
+    let mut similarity: u32 = 0;
+
+    for a in list_a {
+        let mut repeated = 0;
+
+        for b in list_b {
+            if a == b {
+                repeated += 1;
+            }
+        }
+
+        similarity += a * repeated;
+    }
+
+    return similarity;
+}
+
+// size rows of two five digit location ids, about a third of the right list is copied from the
+// left so the similarity score has something to find
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let list_a: Vec<i64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+    let mut rows: Vec<String> = Vec::new();
+
+    for a in &list_a {
+        let b = if rng.chance(30) {
+            *rng.pick(&list_a).unwrap_or(a)
+        } else {
+            rng.range(10000..=99999)
+        };
+        rows.push(format!("{},{}\n", a, b));
+    }
+
+    return rows.concat();
+}
+
+// two whole numbers a row, get_lists only turns away rows that are too short
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    return Ok(Input::read(path)?.each(|line| match columns::<u32>(line)?.len() {
+        2 => Ok(()),
+        count => Err(format!("expected 2 columns but found {}", count)),
+    }));
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(1000)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    match phase!("parse", get_lists("data/input.csv")) {
+        Ok((mut list_a, mut list_b)) => {
+            let min_distance = phase!("part one", minimum_distance(&mut list_a, &mut list_b));
+            println!("minimum distance: {}", min_distance);
+
+            let similarity = phase!("part two", similarity_score(&list_a, &list_b));
+            println!("similarity score: {}", similarity);
+        }
+
+        Err(e) => {
+            println!("Error: failed to retrieve CSV data. {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_minimum_distance() {
+        match get_lists("data/test.csv") {
+            Ok((mut test_a, mut test_b)) => {
+                let result = minimum_distance(&mut test_a, &mut test_b);
+                assert_eq!(result, 11);
+            }
+            Err(e) => {
+                println!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_similarity_score() {
+        match get_lists("data/test.csv") {
+            Ok((test_a, test_b)) => {
+                let result = similarity_score(&test_a, &test_b);
+                assert_eq!(result, 31);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        match read_back(
+            "y2024-day-01",
+            &[("input.csv", generate(&mut Rng::new(1), 200))],
+            get_lists,
+        ) {
+            Ok((list_a, list_b)) => {
+                assert_eq!((list_a.len(), list_b.len()), (200, 200));
+                assert!(similarity_score(&list_a, &list_b) > 0);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-01-broken",
+            &[("input.csv", "3,4\n4\n5,x\n1,2,3\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "expected 2 columns but found 1".to_string()),
+                (Some(3), "column 2 (\"x\") is not a number".to_string()),
+                (Some(4), "expected 2 columns but found 3".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+// https://adventofcode.com/2024/day/2
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::phase;
+use utils::sort;
+use utils::validate::{columns, Input, Problem};
+
+fn is_safe(report: Vec<u32>) -> bool {
+    let mut flip: bool = false;
+
+    for i in 0..(report.len() - 1) {
+        if i == 0 {
+            flip = report[i] > report[i + 1];
+        }
+        if report[i] == report[i + 1] {
+            return false;
+        }
+
+        if i > 0 && flip != (report[i] > report[i + 1]) {
+            return false;
+        }
+
+        let mut sorted_vec: Vec<u32> = vec![report[i], report[i + 1]];
+        let sorted: &mut Vec<u32> = &mut sorted_vec;
+        *sorted = sort(sorted);
+        if sorted[1] - sorted[0] > 3 {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+pub fn reactors_safe(reports: &Vec<Vec<u32>>, dampener_enabled: bool) -> u32 {
+    let mut safe_reactors: u32 = 0;
+
+    for report in reports {
+        let safe = is_safe(report.clone());
+
+        if safe {
+            safe_reactors += 1;
+        } else if dampener_enabled {
+            for i in 0..report.len() {
+                let mut modified_report = report.clone();
+                modified_report.remove(i);
+                let safe_after_removal = is_safe(modified_report);
+
+                if safe_after_removal {
+                    safe_reactors += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    return safe_reactors;
+}
+
+// size reports of 5 to 8 levels, each starts out safe and about half get one or two levels knocked
+// out of line so both parts have something to reject
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut reports: Vec<String> = Vec::new();
+
+    for _ in 0..size {
+        let increasing = rng.chance(50);
+        let mut level = if increasing {
+            rng.range(1..=60)
+        } else {
+            rng.range(40..=99)
+        };
+        let mut levels: Vec<i64> = Vec::new();
+
+        for _ in 0..rng.range(5..=8) {
+            levels.push(level);
+            level += if increasing {
+                rng.range(1..=3)
+            } else {
+                -rng.range(1..=3)
+            };
+        }
+
+        for _ in 0..rng.range(-2..=2).max(0) {
+            let i = rng.below(levels.len());
+            levels[i] = rng.range(1..=99);
+        }
+
+        let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+        reports.push(format!("{}\n", levels.join(",")));
+    }
+
+    return reports.concat();
+}
+
+// a report is any number of levels but every one has to be a whole number
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    return Ok(Input::read(path)?.each(columns::<u32>));
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(1000)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    match phase!("parse", get_csv_data("data/input.csv", false)) {
+        Ok(reports) => {
+            let safe_reactors = phase!("part one", reactors_safe(&reports, false));
+            println!("safe reactors: {}", safe_reactors);
+
+            let safe_reactors_with_dampener = phase!("part two", reactors_safe(&reports, true));
+            println!("safe reactors with dampener: {}", safe_reactors_with_dampener);
+        }
+        Err(e) => {
+            println!("Error: failed to retrieve CSV data. {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_reactors_safe() {
+        match get_csv_data("data/test.csv", false) {
+            Ok(reports) => {
+                let result = reactors_safe(&reports, false);
+                assert_eq!(result, 2);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reactors_safe_with_dampener() {
+        match get_csv_data("data/test.csv", false) {
+            Ok(reports) => {
+                let result = reactors_safe(&reports, true);
+                assert_eq!(result, 4);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        match read_back(
+            "y2024-day-02",
+            &[("input.csv", generate(&mut Rng::new(1), 200))],
+            |path| get_csv_data::<u32>(path, false),
+        ) {
+            Ok(reports) => {
+                let (safe, dampened) = (reactors_safe(&reports, false), reactors_safe(&reports, true));
+
+                assert!(reports.len() == 200 && reports.iter().all(|report| (5..=8).contains(&report.len())));
+                assert!(0 < safe && safe < dampened && dampened < 200);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-02-broken",
+            &[("input.csv", "7,6,4\n1,-2,7\n\n9,7,six\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "column 2 (\"-2\") is not a number".to_string()),
+                (Some(4), "column 3 (\"six\") is not a number".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+// https://adventofcode.com/2024/day/3
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::phase;
+use utils::validate::{Input, Problem};
+
+fn new_toggle(old_toggle: bool, test_string: String) -> bool {
+    let do_length = test_string.split("do()").last().unwrap_or("").len();
+    let dont_length = test_string.split("don't()").last().unwrap_or("").len();
+
+    if do_length == dont_length {
+        return old_toggle;
+    }
+    if do_length < dont_length {
+        return true;
+    }
+
+    return false;
+}
+
+pub fn total_in_line(corrupted_string: &str, mul_toggle: bool) -> u32 {
+    let mut total: u32 = 0;
+    let mut mul_toggled = true;
+
+    let first_pass = corrupted_string.split("mul").skip(1).collect::<Vec<&str>>();
+
+    for part in first_pass {
+        let previous_toggle: bool = mul_toggled;
+        mul_toggled = new_toggle(mul_toggled, part.to_string());
+        if !previous_toggle && mul_toggle {
+            continue;
+        }
+
+        let mut mul = part.split(")").next().unwrap_or(""); // (100,100
+        if mul.len() > 8 || mul.len() < 4 {
+            continue;
+        } // between (1,1 and (100,100
+        mul = mul.split("(").nth(1).unwrap_or(""); // 100,100
+
+        let numbers = mul.split(",").collect::<Vec<&str>>(); // ["100", "100"]
+        if numbers.len() == 2 {
+            if let (Ok(a), Ok(b)) = (numbers[0].parse::<u32>(), numbers[1].parse::<u32>()) {
+                total += a * b;
+            }
+        }
+    }
+
+    return total;
+}
+
+const NOISE: &str = "!@#$%^&*()[]{}<>?:;'~+-/ ";
+const DECOYS: [&str; 8] = ["select", "from", "who", "what", "how", "where", "why", "when"];
+
+// size lines of corrupted memory, real mul(a,b) calls mixed with do() and don't(), calls to other
+// functions, muls that are broken in the ways the puzzle's example shows and plain noise
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for _ in 0..size {
+        let mut line = String::new();
+
+        while line.len() < 3000 {
+            let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+            let decoy = rng.pick(&DECOYS).unwrap_or(&"mul");
+
+            line += &match rng.below(20) {
+                0..=10 => format!("mul({},{})", a, b),
+                11 => "do()".to_string(),
+                12 => "don't()".to_string(),
+                13 => format!("{}({},{})", decoy, a, b),
+                14 => format!("{}()", decoy),
+                15 => format!("mul[{},{}]", a, b),
+                16 => format!("mul({},{}]", a, b),
+                17 => format!("mul ( {} , {} )", a, b),
+                _ => format!("mul({}*", a),
+            };
+
+            for _ in 0..rng.range(0..=3) {
+                line.push(char::from(NOISE.as_bytes()[rng.below(NOISE.len())]));
+            }
+        }
+
+        lines.push(format!("{}\n", line));
+    }
+
+    return lines.concat();
+}
+
+// corrupted memory can hold anything, as long as there is some
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    return Ok(Input::read(path)?.not_empty());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(6)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    match phase!("parse", get_csv_data::<String>("data/input.csv", false)) {
+        Ok(corrupted_code_segments) => {
+            let mut full_corrupted_code = String::new();
+
+            for code in corrupted_code_segments {
+                full_corrupted_code += &code.join(",");
+            }
+
+            let total_without_toggle = phase!("part one", total_in_line(&full_corrupted_code, false));
+            println!("without toggle: {}", total_without_toggle);
+
+            let total_with_toggle = phase!("part two", total_in_line(&full_corrupted_code, true));
+            println!("with toggle: {}", total_with_toggle);
+        }
+        Err(e) => {
+            println!("Error: failed to retrieve CSV data. {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_total_in_line() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let result = total_in_line(input, false);
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_total_in_line_with_toggle() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let result = total_in_line(input, true);
+        assert_eq!(result, 48);
+    }
+
+    #[test]
+    fn test_generate() {
+        match read_back(
+            "y2024-day-03",
+            &[("input.csv", generate(&mut Rng::new(1), 3))],
+            |path| get_csv_data::<String>(path, false),
+        ) {
+            Ok(segments) => {
+                let code: String = segments.iter().map(|code| code.join(",")).collect();
+
+                assert_eq!(segments.len(), 3);
+                assert!(total_in_line(&code, false) > total_in_line(&code, true));
+                assert!(total_in_line(&code, true) > 0);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/input.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in("y2024-day-03-broken", &[("input.csv", "\n\n".to_string())], validate) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(found, vec![(None, "is empty".to_string())]);
+    }
+}
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2024/day/4
 
+use utils::generate::Rng;
 use utils::get_csv_data;
+use utils::phase;
+use utils::validate::{Input, Problem};
 
 fn get_word_search(path: &str) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
     let mut word_search_matrix = get_csv_data::<String>(path, false)?;
@@ -147,13 +150,60 @@ pub fn x_mas_search(word_matrix: &[Vec<String>]) -> u32 {
     return count;
 }
 
+// a size x size square of X, M, A and S with extra XMASes written in every direction on top of the
+// ones that turn up by chance, at least 4 across since the searches assume a whole XMAS fits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let letters = ['X', 'M', 'A', 'S'];
+    let mut grid: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(&letters).unwrap_or(&'X')).collect())
+        .collect();
+
+    for _ in 0..size * size / 40 {
+        let (row, col) = (rng.below(size) as i64, rng.below(size) as i64);
+        let (down, right) = (rng.range(-1..=1), rng.range(-1..=1));
+        let end = (row + down * 3, col + right * 3);
+
+        if (0..size as i64).contains(&end.0) && (0..size as i64).contains(&end.1) {
+            for (k, letter) in letters.iter().enumerate() {
+                grid[(row + down * k as i64) as usize][(col + right * k as i64) as usize] = *letter;
+            }
+        }
+    }
+
+    return grid
+        .iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect();
+}
+
+// a rectangle of letters, only the ones in XMAS can ever be part of a match
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    let input = Input::read(path)?;
+    return Ok([input.rectangular(), input.only(|cell| "XMAS".contains(cell))].concat());
+}
+
 fn main() {
-    match get_word_search("data/input.csv") {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(140)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    match phase!("parse", get_word_search("data/input.csv")) {
         Ok(word_search_matrix) => {
-            let word_search_count = word_search(&word_search_matrix);
+            let word_search_count = phase!("part one", word_search(&word_search_matrix));
             println!("word search count: {}", word_search_count);
 
-            let x_mas_count = x_mas_search(&word_search_matrix);
+            let x_mas_count = phase!("part two", x_mas_search(&word_search_matrix));
             println!("x-mas count: {}", x_mas_count);
         }
         Err(e) => {
@@ -165,6 +215,8 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
 
     #[test]
     fn test_word_search() {
@@ -191,4 +243,49 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_generate() {
+        match read_back(
+            "y2024-day-04",
+            &[("input.csv", generate(&mut Rng::new(1), 40))],
+            get_word_search,
+        ) {
+            Ok(word_search_matrix) => {
+                assert!(word_search_matrix.len() == 40 && word_search_matrix.iter().all(|row| row.len() == 40));
+                assert!(word_search(&word_search_matrix) > 40);
+                assert!(x_mas_search(&word_search_matrix) > 0);
+            }
+            Err(e) => {
+                panic!("Error: failed to retrieve CSV data. {}", e);
+            }
+        }
+
+        let small = generate(&mut Rng::new(1), 2);
+        assert!(small.lines().count() == 4 && small.lines().all(|row| row.len() == 4));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-04-broken",
+            &[("input.csv", "XMAS\nSAMX\nMAX\nXMAZ\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(3), "row is 3 wide but the first is 4".to_string()),
+                (Some(4), "unexpected 'Z' in column 4".to_string())
+            ]
+        );
+    }
 }
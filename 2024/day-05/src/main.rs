@@ -0,0 +1,251 @@
+// https://adventofcode.com/2024/day/5
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::phase;
+use utils::validate::{columns, Input, Problem};
+
+fn get_rules(path: &str) -> Result<HashMap<String, bool>, Box<dyn Error>> {
+    let mut rules = HashMap::new();
+
+    let rules_vec: Vec<Vec<u32>> = get_csv_data(path, false)?;
+
+    for rule in rules_vec {
+        rules.insert(format!("{}|{}", rule[0], rule[1]), true);
+    }
+
+    return Ok(rules);
+}
+
+fn is_in_order(rules: &HashMap<String, bool>, update: &[u32]) -> bool {
+    for i in 0..update.len() {
+        for j in 0..update.len() {
+            if i == j {
+                continue;
+            }
+            let possible_rule = if i > j {
+                format!("{}|{}", update[i], update[j])
+            } else {
+                format!("{}|{}", update[j], update[i])
+            };
+
+            if rules.contains_key(&possible_rule) {
+                return false;
+            }
+        }
+    }
+
+    return true;
+}
+
+// i'm proud of this! i adapted an algorithm to custom sorting rules!
+fn sort_update(rules: &HashMap<String, bool>, update: &mut Vec<u32>) {
+    if update.len() <= 1 {
+        return;
+    }
+
+    let pivot = update.pop().unwrap_or(0);
+    let mut less_than_pivot: Vec<u32> = Vec::new();
+    let mut greater_than_pivot: Vec<u32> = Vec::new();
+
+    for &x in update.iter() {
+        let possible_rule = format!("{}|{}", x, pivot);
+        if rules.contains_key(&possible_rule) {
+            less_than_pivot.push(x);
+        } else {
+            greater_than_pivot.push(x);
+        }
+    }
+
+    sort_update(rules, &mut less_than_pivot);
+    sort_update(rules, &mut greater_than_pivot);
+
+    update.clear();
+    update.extend(less_than_pivot);
+    update.push(pivot);
+    update.extend(greater_than_pivot);
+}
+
+pub fn count_of_middle_numbers(rules: &HashMap<String, bool>, updates: &mut [Vec<u32>]) -> (u32, u32) {
+    let mut sorted_count: u32 = 0;
+    let mut unsorted_count: u32 = 0;
+
+    for update in updates {
+        if is_in_order(rules, update) {
+            sorted_count += update[update.len() / 2];
+        } else {
+            sort_update(rules, update);
+            unsorted_count += update[update.len() / 2];
+        }
+    }
+
+    return (sorted_count, unsorted_count);
+}
+
+// rules ordering every pair of 49 two digit pages the way one shuffle of them does, and size updates
+// of an odd number of those pages, about half already in order
+pub fn generate(rng: &mut Rng, size: usize) -> (String, String) {
+    let mut pages: Vec<i64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules: Vec<String> = Vec::new();
+    for i in 0..pages.len() {
+        for j in i + 1..pages.len() {
+            rules.push(format!("{},{}\n", pages[i], pages[j]));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut updates: Vec<String> = Vec::new();
+    for _ in 0..size {
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut update);
+        update.truncate(rng.range(2..=11) as usize * 2 + 1);
+
+        if rng.chance(50) {
+            update.sort();
+        }
+
+        let update: Vec<String> = update.iter().map(|page| pages[*page].to_string()).collect();
+        updates.push(format!("{}\n", update.join(",")));
+    }
+
+    return (rules.concat(), updates.concat());
+}
+
+// rules are pairs of pages, updates need an odd number of pages to have a middle one
+pub fn validate(directory: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let rules = Input::read(&format!("{}/rules.csv", directory))?.each(|line| match columns::<u32>(line)?.len() {
+        2 => Ok(()),
+        count => Err(format!("expected 2 pages but found {}", count)),
+    });
+    let updates = Input::read(&format!("{}/updates.csv", directory))?.each(|line| match columns::<u32>(line)?.len() {
+        count if count % 2 == 1 => Ok(()),
+        count => Err(format!("{} pages has no middle page", count)),
+    });
+
+    return Ok([rules, updates].concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let (rules, updates) = generate(&mut request.rng(), request.size_or(194));
+        if let Err(e) = request.save(&[("input/rules.csv", rules), ("input/updates.csv", updates)]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input")) {
+        return;
+    }
+
+    let rules = match phase!("parse", get_rules("data/input/rules.csv")) {
+        Ok(rules) => rules,
+        Err(e) => {
+            println!("Error: Failed to retrieve rules data. {}", e);
+            return;
+        }
+    };
+
+    let mut updates = match phase!("parse", get_csv_data("data/input/updates.csv", false)) {
+        Ok(updates) => updates,
+        Err(e) => {
+            println!("Error: Failed to retrieve updates data. {}", e);
+            return;
+        }
+    };
+
+    let (sorted_count, unsorted_count) = phase!("both parts", count_of_middle_numbers(&rules, &mut updates));
+    println!("sorted count of middle numbers: {}", sorted_count);
+    println!("unsorted count of middle numbers: {}", unsorted_count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_count_of_middle_numbers() {
+        let rules: HashMap<String, bool> = match get_rules("data/test/rules.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve rules data. {}", e);
+            }
+        };
+
+        let mut updates: Vec<Vec<u32>> = match get_csv_data("data/test/updates.csv", false) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve updates data. {}", e);
+            }
+        };
+
+        let (sorted_count, unsorted_count) = count_of_middle_numbers(&rules, &mut updates);
+        assert_eq!(sorted_count, 143);
+        assert_eq!(unsorted_count, 123);
+    }
+
+    #[test]
+    fn test_generate() {
+        let (rules, updates) = generate(&mut Rng::new(1), 100);
+        let files = [("input/rules.csv", rules), ("input/updates.csv", updates)];
+
+        let (rules, mut updates) = match read_back("y2024-day-05", &files, |input| {
+            Ok((
+                get_rules(&format!("{}/rules.csv", input))?,
+                get_csv_data::<u32>(&format!("{}/updates.csv", input), false)?,
+            ))
+        }) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!(
+                    "Error: Failed to retrieve the generated rules and updates in test_generate. {}",
+                    e
+                );
+            }
+        };
+
+        assert_eq!(rules.len(), 49 * 48 / 2);
+        assert!(updates.iter().all(|update| update.len() % 2 == 1));
+
+        let (sorted_count, unsorted_count) = count_of_middle_numbers(&rules, &mut updates);
+        assert!(sorted_count > 0 && unsorted_count > 0);
+        assert!(updates.iter().all(|update| is_in_order(&rules, update)));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-05-broken",
+            &[
+                ("input/rules.csv", "47,53\n97|13\n".to_string()),
+                ("input/updates.csv", "75,47,61\n75,29\n".to_string()),
+            ],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "column 1 (\"97|13\") is not a number".to_string()),
+                (Some(2), "2 pages has no middle page".to_string())
+            ]
+        );
+    }
+}
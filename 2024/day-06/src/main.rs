@@ -0,0 +1,618 @@
+// https://adventofcode.com/2024/day/6
+
+use rayon::prelude::*;
+use std::error::Error;
+
+use utils::bits::BitGrid;
+use utils::generate::Rng;
+use utils::geom::Point;
+use utils::get_csv_data;
+use utils::image::Renderer;
+use utils::sim::{Cell, CellKind, Frame, Simulation};
+use utils::svg::Svg;
+use utils::validate::{Input, Problem};
+use utils::variant::Registry;
+use utils::{counter, phase, step};
+
+#[derive(PartialEq, Clone)]
+pub enum MapState {
+    Wall,
+    Explored,
+    Unexplored,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum GuardDirection {
+    Left,
+    Up,
+    Down,
+    Right,
+}
+
+type MapResult = Result<(Vec<Vec<MapState>>, (usize, usize)), Box<dyn Error>>;
+// an obstacle to try along with where the guard is and which way it faces when it first walks into it
+type Trial = ((usize, usize), (usize, usize), GuardDirection);
+
+fn get_map(path: &str) -> MapResult {
+    let input_map: Vec<Vec<String>> = get_csv_data(path, false)?;
+    let lines: Vec<String> = input_map.iter().map(|row| row[0].clone()).collect();
+
+    Ok(map_from_lines(&lines))
+}
+
+fn map_from_lines(lines: &[String]) -> (Vec<Vec<MapState>>, (usize, usize)) {
+    let mut map: Vec<Vec<MapState>> = Vec::new();
+    let mut guard_x: usize = 0;
+    let mut guard_y: usize = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut row: Vec<MapState> = Vec::new();
+        for (j, spot) in line.chars().enumerate() {
+            if spot == '^' {
+                guard_x = i;
+                guard_y = j;
+                row.push(MapState::Explored);
+            } else if spot == '#' {
+                row.push(MapState::Wall);
+            } else {
+                row.push(MapState::Unexplored);
+            }
+        }
+        map.push(row);
+    }
+
+    return (map, (guard_x, guard_y));
+}
+
+fn copy_map(map: &[Vec<MapState>]) -> Vec<Vec<MapState>> {
+    let mut map_copy: Vec<Vec<MapState>> = Vec::new();
+
+    for row in map {
+        map_copy.push(row.clone());
+    }
+
+    return map_copy;
+}
+
+fn turn_right(guard_direction: GuardDirection) -> GuardDirection {
+    return match guard_direction {
+        GuardDirection::Left => GuardDirection::Up,
+        GuardDirection::Up => GuardDirection::Right,
+        GuardDirection::Right => GuardDirection::Down,
+        GuardDirection::Down => GuardDirection::Left,
+    };
+}
+
+// turns right in front of a wall or takes a step, None once the guard walks off the map
+fn move_guard(
+    map: &[Vec<MapState>],
+    (guard_x, guard_y): (usize, usize),
+    guard_direction: GuardDirection,
+) -> Option<((usize, usize), GuardDirection)> {
+    let (mut go_to_x, mut go_to_y) = (guard_x, guard_y);
+
+    if guard_direction == GuardDirection::Left {
+        if guard_y == 0 {
+            return None;
+        }
+        go_to_y = guard_y - 1;
+    } else if guard_direction == GuardDirection::Up {
+        if guard_x == 0 {
+            return None;
+        }
+        go_to_x = guard_x - 1;
+    } else if guard_direction == GuardDirection::Down {
+        if guard_x + 1 == map.len() {
+            return None;
+        }
+        go_to_x = guard_x + 1;
+    } else if guard_direction == GuardDirection::Right {
+        if guard_y + 1 == map[0].len() {
+            return None;
+        }
+        go_to_y = guard_y + 1;
+    }
+
+    if map[go_to_x][go_to_y] == MapState::Wall {
+        return Some(((guard_x, guard_y), turn_right(guard_direction)));
+    }
+
+    return Some(((go_to_x, go_to_y), guard_direction));
+}
+
+pub fn get_unique_positions(
+    mut map: Vec<Vec<MapState>>,
+    initial_x: usize,
+    initial_y: usize,
+) -> (Vec<Vec<MapState>>, bool, u32) {
+    let mut guard_x = initial_x;
+    let mut guard_y = initial_y;
+    let mut guard_direction = GuardDirection::Up;
+    // one plane per direction, being somewhere facing the same way twice means the guard loops
+    let mut visited = BitGrid::with_planes(map.len(), map[0].len(), 4);
+    let mut count: u32 = 0;
+
+    while let Some(((go_to_x, go_to_y), direction)) = move_guard(&map, (guard_x, guard_y), guard_direction) {
+        (guard_x, guard_y, guard_direction) = (go_to_x, go_to_y, direction);
+
+        map[guard_x][guard_y] = MapState::Explored;
+        if !visited.insert_on((guard_x, guard_y), guard_direction as usize) {
+            return (map, true, 0);
+        }
+    }
+
+    for row in &map {
+        for col in row {
+            if *col == MapState::Explored {
+                count += 1;
+            }
+        }
+    }
+
+    return (map, false, count);
+}
+
+// whether the guard, starting from here with an extra obstacle on the map, ends up walking the same
+// way over the same spot twice
+fn walks_in_loop(
+    map: &[Vec<MapState>],
+    obstacle: (usize, usize),
+    guard: (usize, usize),
+    guard_direction: GuardDirection,
+) -> bool {
+    let mut visited = BitGrid::with_planes(map.len(), map[0].len(), 4);
+    let (mut guard, mut guard_direction) = (guard, guard_direction);
+
+    while let Some((go_to, direction)) = move_guard(map, guard, guard_direction) {
+        if go_to == obstacle {
+            guard_direction = turn_right(guard_direction);
+        } else {
+            (guard, guard_direction) = (go_to, direction);
+        }
+
+        if !visited.insert_on(guard, guard_direction as usize) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// every spot on the guard's route where a new obstacle would send the guard round in a loop, the
+// route is only walked once and each spot is tried from where the guard first walks into it rather
+// than from the start
+pub fn get_loop_obstacles(map: &[Vec<MapState>], initial_x: usize, initial_y: usize) -> Vec<(usize, usize)> {
+    let mut tried = BitGrid::new(map.len(), map[0].len());
+    let mut visited = BitGrid::with_planes(map.len(), map[0].len(), 4);
+    let mut trials: Vec<Trial> = Vec::new();
+    let (mut guard, mut guard_direction) = ((initial_x, initial_y), GuardDirection::Up);
+
+    tried.insert(guard);
+    while let Some((go_to, direction)) = move_guard(map, guard, guard_direction) {
+        if go_to != guard && tried.insert(go_to) {
+            trials.push((go_to, guard, guard_direction));
+        }
+        (guard, guard_direction) = (go_to, direction);
+
+        // a route that already loops has no obstacles worth adding
+        if !visited.insert_on(guard, guard_direction as usize) {
+            return Vec::new();
+        }
+    }
+
+    return trials
+        .par_iter()
+        .filter(|(obstacle, guard, guard_direction)| {
+            step!(
+                "obstacle trial",
+                walks_in_loop(map, *obstacle, *guard, *guard_direction)
+            )
+        })
+        .map(|(obstacle, _, _)| *obstacle)
+        .collect();
+}
+
+pub fn total_possible_loops(map: Vec<Vec<MapState>>, initial_x: usize, initial_y: usize) -> u32 {
+    let count = get_loop_obstacles(&map, initial_x, initial_y).len() as u32;
+
+    counter!("loops detected", count);
+    return count;
+}
+
+// the reference, puts each obstacle on a fresh copy of the map and walks the whole route again
+fn get_loop_obstacles_by_trial(map: &[Vec<MapState>], initial_x: usize, initial_y: usize) -> Vec<(usize, usize)> {
+    let (explored_map, _, _) = get_unique_positions(copy_map(map), initial_x, initial_y);
+
+    return explored_map
+        .par_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(|(j, state)| {
+                    if *state == MapState::Explored && (i, j) != (initial_x, initial_y) {
+                        let mut new_map = copy_map(map);
+                        new_map[i][j] = MapState::Wall;
+                        let (_, looped, _) = get_unique_positions(new_map, initial_x, initial_y);
+                        if looped {
+                            Some((i, j))
+                        } else {
+                            None
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<(usize, usize)>>()
+        })
+        .flatten()
+        .collect();
+}
+
+// the trial is the reference the single walk is checked against
+pub fn get_implementations(map: &[Vec<MapState>], initial_x: usize, initial_y: usize) -> Registry<'_> {
+    return Registry::new()
+        .add(1, "fast", move || {
+            get_unique_positions(copy_map(map), initial_x, initial_y).2
+        })
+        .add(2, "fast", move || {
+            total_possible_loops(copy_map(map), initial_x, initial_y)
+        })
+        .add(2, "trial", move || {
+            get_loop_obstacles_by_trial(map, initial_x, initial_y).len()
+        });
+}
+
+// the guard's route over the lab with the obstacles that would cause a loop filled in
+pub fn get_patrol_svg(map: &[Vec<MapState>], initial_x: usize, initial_y: usize) -> Svg {
+    let mut svg = Svg::new(map.len(), map.first().map_or(0, |row| row.len()), 8);
+    if map.is_empty() {
+        return svg;
+    }
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            if map[i][j] == MapState::Wall {
+                svg.fill(Point::from_index((i, j)), "dimgrey");
+            }
+        }
+    }
+
+    for obstacle in get_loop_obstacles(map, initial_x, initial_y) {
+        svg.fill(Point::from_index(obstacle), "crimson");
+    }
+
+    let mut patrol = Patrol::new(map, initial_x, initial_y);
+    let mut route: Vec<Point> = vec![Point::from_index((initial_x, initial_y))];
+
+    while !patrol.is_done() {
+        patrol.step();
+        if let Some(guard) = patrol.guard {
+            route.push(Point::from_index(guard));
+        }
+    }
+
+    svg.polyline(&route, "steelblue", 2.0)
+        .label(Point::from_index((initial_x, initial_y)), "^", "black");
+
+    return svg;
+}
+
+pub struct Patrol {
+    map: Vec<Vec<MapState>>,
+    guard: Option<(usize, usize)>,
+    direction: GuardDirection,
+    steps: u32,
+}
+
+impl Patrol {
+    pub fn new(map: &[Vec<MapState>], initial_x: usize, initial_y: usize) -> Patrol {
+        return Patrol {
+            map: map.to_owned(),
+            guard: Some((initial_x, initial_y)),
+            direction: GuardDirection::Up,
+            steps: 0,
+        };
+    }
+}
+
+impl Simulation for Patrol {
+    fn step(&mut self) {
+        let moved = self
+            .guard
+            .and_then(|guard| move_guard(&self.map, guard, self.direction));
+
+        self.guard = moved.map(|(guard, _)| guard);
+        if let Some(((x, y), direction)) = moved {
+            self.map[x][y] = MapState::Explored;
+            self.direction = direction;
+        }
+        self.steps += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        return self.guard.is_none();
+    }
+
+    fn render(&self) -> Frame {
+        let mut frame: Frame = self
+            .map
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|state| match state {
+                        MapState::Wall => Cell::new('#', CellKind::Wall),
+                        MapState::Explored => Cell::new('X', CellKind::Visited),
+                        MapState::Unexplored => Cell::new('.', CellKind::Empty),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if let Some((x, y)) = self.guard {
+            let glyph = match self.direction {
+                GuardDirection::Left => '<',
+                GuardDirection::Up => '^',
+                GuardDirection::Down => 'v',
+                GuardDirection::Right => '>',
+            };
+            frame[x][y] = Cell::new(glyph, CellKind::Actor);
+        }
+
+        return frame;
+    }
+
+    fn caption(&self) -> String {
+        return format!("step {}", self.steps);
+    }
+}
+
+// a size x size lab with about one spot in twenty blocked and the guard facing up somewhere free,
+// labs where the guard would never get out are thrown away and drawn again, a lab is at least one
+// spot across so there is somewhere to put the guard
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let mut lab: Vec<Vec<char>> = (0..size)
+            .map(|_| (0..size).map(|_| if rng.chance(5) { '#' } else { '.' }).collect())
+            .collect();
+
+        let (row, col) = (rng.below(size), rng.below(size));
+        if let Some(spot) = lab.get_mut(row).and_then(|line| line.get_mut(col)) {
+            *spot = '^';
+        }
+
+        let lines: Vec<String> = lab.iter().map(|line| line.iter().collect()).collect();
+        let (map, (initial_x, initial_y)) = map_from_lines(&lines);
+        let (_, looped, _) = get_unique_positions(map, initial_x, initial_y);
+
+        if !looped {
+            return lines.iter().map(|line| format!("{}\n", line)).collect();
+        }
+    }
+}
+
+// map_from_lines takes each row's width from its own line so the lab only has to be
+// rectangular, with exactly one guard
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let input = Input::read(path)?;
+    return Ok([
+        input.rectangular(),
+        input.only(|cell| ".#^".contains(cell)),
+        input.exactly('^', 1),
+    ]
+    .concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(130)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let (map, (initial_x, initial_y)) = match phase!("parse", get_map("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve map data. {}", e);
+            return;
+        }
+    };
+
+    if utils::sim::requested() {
+        if let Err(e) = utils::sim::play(&mut Patrol::new(&map, initial_x, initial_y)) {
+            println!("Error: Failed to play the simulation. {}", e);
+        }
+        return;
+    }
+
+    if !utils::svg::save_requested(|| get_patrol_svg(&map, initial_x, initial_y)) {
+        return;
+    }
+
+    let renderer = Renderer {
+        cell_size: 3,
+        every: 20,
+        ..Renderer::default()
+    };
+    if !utils::image::render_requested(|| Patrol::new(&map, initial_x, initial_y), &renderer) {
+        return;
+    }
+
+    if let Some(selected) = utils::variant::requested() {
+        if let Err(e) = utils::variant::compare(&get_implementations(&map, initial_x, initial_y), &selected) {
+            println!("Error: Failed to compare implementations. {}", e);
+        }
+        return;
+    }
+
+    let (_, _, unique_positions) = phase!("part one", get_unique_positions(copy_map(&map), initial_x, initial_y));
+    println!("unique positions: {}", unique_positions);
+
+    let possible_loops = phase!("part two", total_possible_loops(copy_map(&map), initial_x, initial_y));
+    println!("possible loops: {}", possible_loops);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::check::proptest::prelude::*;
+    use utils::check::{agree, grid, place};
+    use utils::generate::{read_back, Rng};
+    use utils::validate::problems_in;
+    use utils::variant::agreed;
+
+    #[test]
+    fn test_get_unique_positions() {
+        let (map, (initial_x, initial_y)) = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map data in test_get_unique_positions. {}", e);
+            }
+        };
+
+        let (_, looped, result) = get_unique_positions(map, initial_x, initial_y);
+        assert!(!looped);
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_total_possible_loops() {
+        let (map, (initial_x, initial_y)) = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map data in test_total_possible_loops. {}", e);
+            }
+        };
+
+        let result = total_possible_loops(map, initial_x, initial_y);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_get_patrol_svg() {
+        let (map, (initial_x, initial_y)) = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map data in test_get_patrol_svg. {}", e);
+            }
+        };
+
+        let svg = get_patrol_svg(&map, initial_x, initial_y).render();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"80\" height=\"80\""));
+        assert_eq!(svg.matches("fill=\"dimgrey\"").count(), 8);
+        assert_eq!(svg.matches("fill=\"crimson\"").count(), 6);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert!(svg.contains("<text x=\"36\" y=\"52\""));
+
+        assert_eq!(get_patrol_svg(&[], 0, 0).render(), Svg::new(0, 0, 8).render());
+    }
+
+    #[test]
+    fn test_obstacles_match_trial() {
+        // the puzzle promises the guard walks off the map in the end
+        let lab = place(grid(1..=8, 1..=8, &[(4, '.'), (1, '#')]), '^').prop_filter("the guard loops", |lab| {
+            let (map, (initial_x, initial_y)) = map_from_lines(lab);
+            let (_, looped, _) = get_unique_positions(map, initial_x, initial_y);
+            !looped
+        });
+        let sorted = |mut obstacles: Vec<(usize, usize)>| {
+            obstacles.sort();
+            obstacles
+        };
+
+        if let Err(e) = agree(
+            lab,
+            |lab| {
+                let (map, (initial_x, initial_y)) = map_from_lines(lab);
+                sorted(get_loop_obstacles_by_trial(&map, initial_x, initial_y))
+            },
+            |lab| {
+                let (map, (initial_x, initial_y)) = map_from_lines(lab);
+                sorted(get_loop_obstacles(&map, initial_x, initial_y))
+            },
+        ) {
+            panic!("Error: Failed to match the trial in test_obstacles_match_trial. {}", e);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let (map, (initial_x, initial_y)) = match read_back(
+            "y2024-day-06",
+            &[("input.csv", generate(&mut Rng::new(1), 30))],
+            get_map,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map data in test_generate. {}", e);
+            }
+        };
+
+        let (_, looped, unique_positions) = get_unique_positions(copy_map(&map), initial_x, initial_y);
+        assert!(map.len() == 30 && map.iter().all(|row| row.len() == 30));
+        assert!(!looped && unique_positions > 0);
+        assert_eq!(generate(&mut Rng::new(1), 0), "^\n");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-06-broken",
+            &[("input.csv", "....\n.#..\n.x.\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(3), "row is 3 wide but the first is 4".to_string()),
+                (Some(3), "unexpected 'x' in column 2".to_string()),
+                (None, "expected 1 '^' but found 0".to_string())
+            ]
+        );
+
+        let wide = problems_in(
+            "y2024-day-06-wide",
+            &[("input.csv", "....#.\n.^....\n".to_string())],
+            validate,
+        );
+        assert_eq!(wide.ok(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        let (map, (initial_x, initial_y)) = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!(
+                    "Error: Failed to retrieve map data in test_implementations_agree. {}",
+                    e
+                );
+            }
+        };
+
+        let outcomes = match get_implementations(&map, initial_x, initial_y).run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run the implementations. {}", e);
+            }
+        };
+        assert_eq!(agreed(&outcomes), Ok(vec![(1, "41".to_string()), (2, "6".to_string())]));
+    }
+}
@@ -0,0 +1,276 @@
+// https://adventofcode.com/2024/day/7
+
+use rayon::prelude::*;
+use std::error::Error;
+
+use utils::generate::Rng;
+use utils::scan::{integers, ScanError};
+use utils::validate::{Input, Problem};
+use utils::{get_lines, phase, scan};
+
+pub struct Calibration {
+    evaluating_to: u64,
+    equation: Vec<u64>,
+}
+
+pub fn get_calibrations(path: &str) -> Result<Vec<Calibration>, Box<dyn Error>> {
+    return Ok(get_lines(path)?
+        .iter()
+        .map(|line| {
+            let (evaluating_to, equation) = scan!(line, "{}: {}", u64, String)?;
+            Ok(Calibration {
+                evaluating_to,
+                equation: integers(&equation)?,
+            })
+        })
+        .collect::<Result<Vec<Calibration>, ScanError>>()?);
+}
+
+fn concatinate_numbers(a: &u64, b: &u64) -> u64 {
+    let number_string = format!("{}{}", a, b);
+    return number_string.parse::<u64>().unwrap_or(0);
+}
+
+fn possible_equations_for_calibration(
+    calibration: &Calibration,
+    compare: u64,
+    mut count: u64,
+    concatenation: &bool,
+) -> u64 {
+    if calibration.equation.len() > 1 {
+        let mut new_equation = calibration.equation.clone();
+        new_equation.remove(0);
+
+        let new_calibration = Calibration {
+            evaluating_to: calibration.evaluating_to,
+            equation: new_equation,
+        };
+
+        count = possible_equations_for_calibration(
+            &new_calibration,
+            compare + calibration.equation[0],
+            count,
+            concatenation,
+        );
+        count = possible_equations_for_calibration(
+            &new_calibration,
+            compare * calibration.equation[0],
+            count,
+            concatenation,
+        );
+        if *concatenation {
+            count = possible_equations_for_calibration(
+                &new_calibration,
+                concatinate_numbers(&compare, &calibration.equation[0]),
+                count,
+                concatenation,
+            );
+        }
+
+        return count;
+    } else {
+        if compare + calibration.equation[0] == calibration.evaluating_to {
+            count += 1
+        }
+        if compare * calibration.equation[0] == calibration.evaluating_to {
+            count += 1
+        }
+        if *concatenation && concatinate_numbers(&compare, &calibration.equation[0]) == calibration.evaluating_to {
+            count += 1;
+        }
+
+        return count;
+    }
+}
+
+pub fn total_calibration_result(calibrations: &Vec<Calibration>, concatenation: bool) -> u64 {
+    return calibrations
+        .par_iter()
+        .map(|calibration| {
+            let possible = possible_equations_for_calibration(calibration, 0, 0, &concatenation);
+            if possible > 0 {
+                calibration.evaluating_to
+            } else {
+                0
+            }
+        })
+        .sum();
+}
+
+// the most any mix of +, * and || can reach, None once it no longer fits in a u64
+fn largest_result(equation: &[u64]) -> Option<u64> {
+    let mut largest: u64 = 0;
+
+    for number in equation {
+        largest = (largest + 1).checked_mul(10u64.checked_pow(number.to_string().len() as u32)?)?;
+    }
+
+    return Some(largest);
+}
+
+// size equations of 2 to 12 mostly small numbers, about two thirds evaluate to their target with
+// some mix of +, * and || and the rest are nudged off it, equations that could overflow while being
+// searched are drawn again
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut calibrations: Vec<String> = Vec::new();
+
+    while calibrations.len() < size {
+        let equation: Vec<u64> = (0..rng.range(2..=12))
+            .map(|_| match rng.below(10) {
+                0..=5 => rng.range(1..=9),
+                6..=8 => rng.range(10..=99),
+                _ => rng.range(100..=999),
+            } as u64)
+            .collect();
+
+        if largest_result(&equation).is_none() {
+            continue;
+        }
+
+        let mut evaluating_to = equation[0];
+        for number in &equation[1..] {
+            evaluating_to = match rng.below(3) {
+                0 => evaluating_to + number,
+                1 => evaluating_to * number,
+                _ => concatinate_numbers(&evaluating_to, number),
+            };
+        }
+        if rng.chance(33) {
+            evaluating_to += rng.range(1..=9) as u64;
+        }
+
+        let equation: Vec<String> = equation.iter().map(|number| number.to_string()).collect();
+        calibrations.push(format!("{}: {}\n", evaluating_to, equation.join(" ")));
+    }
+
+    return calibrations.concat();
+}
+
+// a test value and at least one number to put operators between
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    return Ok(Input::read(path)?.each(|line| {
+        let (_, equation) = scan!(line, "{}: {}", u64, String).map_err(|e| e.to_string())?;
+        match integers::<u64>(&equation) {
+            Ok(numbers) if !numbers.is_empty() => Ok(()),
+            Ok(_) => Err("no numbers to combine".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }));
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(850)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let calibrations = match phase!("parse", get_calibrations("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve calibrations. {}", e);
+            return;
+        }
+    };
+
+    let calibration_result = phase!("part one", total_calibration_result(&calibrations, false));
+    println!("calibration result: {}", calibration_result);
+
+    let calibration_result_with_concatenation = phase!("part two", total_calibration_result(&calibrations, true));
+    println!(
+        "calibration result with concatenation: {}",
+        calibration_result_with_concatenation
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_total_calibration_result() {
+        let calibrations = match get_calibrations("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve calibrations. {}", e);
+                return;
+            }
+        };
+
+        let result = total_calibration_result(&calibrations, false);
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn test_total_calibration_result_with_concatenation() {
+        let calibrations = match get_calibrations("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve calibrations. {}", e);
+                return;
+            }
+        };
+
+        let result = total_calibration_result(&calibrations, true);
+        assert_eq!(result, 11387);
+    }
+
+    #[test]
+    fn test_generate() {
+        let calibrations = match read_back(
+            "y2024-day-07",
+            &[("input.csv", generate(&mut Rng::new(1), 100))],
+            get_calibrations,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve calibrations in test_generate. {}", e);
+            }
+        };
+
+        assert_eq!(calibrations.len(), 100);
+        assert!(calibrations
+            .iter()
+            .all(|calibration| largest_result(&calibration.equation).is_some()));
+
+        let (result, result_with_concatenation) = (
+            total_calibration_result(&calibrations, false),
+            total_calibration_result(&calibrations, true),
+        );
+        assert!(0 < result && result < result_with_concatenation);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-07-broken",
+            &[("input.csv", "190: 10 19\n3267 81 40\n83: \n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "\"3267 81 40\" does not match \"{}: {}\"".to_string()),
+                (Some(3), "no numbers to combine".to_string())
+            ]
+        );
+    }
+}
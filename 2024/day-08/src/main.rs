@@ -0,0 +1,379 @@
+// https://adventofcode.com/2024/day/8
+
+use std::convert::From;
+use std::error::Error;
+
+use utils::bits::BitGrid;
+use utils::generate::Rng;
+use utils::geom::{Point, Vector};
+use utils::get_csv_data;
+use utils::phase;
+use utils::svg::{hue, Svg};
+use utils::validate::{Input, Problem};
+
+pub fn get_map(path: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let mut map: Vec<Vec<String>> = get_csv_data(path, false)?;
+
+    for i in 0..map.len() {
+        let mut row: Vec<String> = map[i][0].split("").map(String::from).collect();
+        if row.first() == Some(&String::from("")) {
+            row.remove(0);
+        }
+
+        if row.last() == Some(&String::from("")) {
+            row.pop();
+        }
+
+        map[i] = row;
+    }
+
+    return Ok(map);
+}
+
+fn copy_map(map: &Vec<Vec<String>>) -> Vec<Vec<String>> {
+    let mut map_copy: Vec<Vec<String>> = Vec::new();
+
+    for row in map {
+        map_copy.push(row.clone());
+    }
+
+    return map_copy;
+}
+
+fn count_antinodes(mut map: Vec<Vec<String>>, antenna: &String, index: Point) -> u32 {
+    let mut count: u32 = 0;
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let here = Point::from_index((i, j));
+
+            if here == index || map[i][j] != *antenna {
+                continue;
+            }
+
+            let antinode = index + (index - here);
+            if let Some(spot) = antinode.get_mut(&mut map).filter(|spot| **spot == *".") {
+                *spot = "#".to_string();
+            }
+        }
+    }
+
+    for row in map {
+        for col in row {
+            if col == *"#" {
+                count += 1;
+            }
+        }
+    }
+
+    return count;
+}
+
+// marks every spot from start + step onwards until it walks off the map
+fn mark_line(
+    map: &mut [Vec<String>],
+    antenna_on_antinode: &mut BitGrid,
+    antenna: &String,
+    start: Point,
+    step: Vector,
+) -> u32 {
+    let mut count: u32 = 0;
+    let mut spot = start + step;
+
+    while let Some((i, j)) = spot.index(map.len(), map[0].len()) {
+        if map[i][j] == *"." {
+            map[i][j] = "#".to_string();
+        } else if map[i][j] == *antenna && antenna_on_antinode.insert((i, j)) {
+            count += 1;
+        }
+
+        spot += step;
+    }
+
+    return count;
+}
+
+fn count_antinodes_in_line(
+    mut map: Vec<Vec<String>>,
+    antenna_on_antinode: &mut BitGrid,
+    antenna: &String,
+    index: Point,
+) -> (Vec<Vec<String>>, u32) {
+    let mut count: u32 = 0;
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let here = Point::from_index((i, j));
+
+            if here == index || map[i][j] != *antenna {
+                continue;
+            }
+
+            // every grid point on the line counts, so step by the smallest whole offset along it
+            let step = (index - here).reduced();
+
+            count += mark_line(&mut map, antenna_on_antinode, antenna, here, step);
+            count += mark_line(&mut map, antenna_on_antinode, antenna, here, -step);
+        }
+    }
+
+    return (map, count);
+}
+
+pub fn get_count_of_all_antinodes(map: &Vec<Vec<String>>) -> u32 {
+    let mut count: u32 = 0;
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let antenna = &map[i][j];
+
+            if *antenna != *"." && *antenna != *"#" {
+                count += count_antinodes(copy_map(map), antenna, Point::from_index((i, j)));
+            }
+        }
+    }
+
+    return count;
+}
+
+pub fn get_count_of_all_antinodes_in_line(mut map: Vec<Vec<String>>) -> u32 {
+    let mut antenna_on_antinode = BitGrid::new(map.len(), map[0].len());
+    let mut count: u32 = 0;
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let antenna = &map[i][j];
+
+            if *antenna != *"." && *antenna != *"#" {
+                let new_count;
+                (map, new_count) = count_antinodes_in_line(
+                    copy_map(&map),
+                    &mut antenna_on_antinode,
+                    antenna,
+                    Point::from_index((i, j)),
+                );
+                count += new_count;
+            }
+        }
+    }
+
+    for row in map {
+        for col in row {
+            if *col == *"#" {
+                count += 1;
+            }
+        }
+    }
+
+    return count;
+}
+
+// every line through two antennas of the same frequency, drawn edge to edge in that frequency's
+// colour
+pub fn get_antenna_svg(map: &[Vec<String>]) -> Svg {
+    let mut svg = Svg::new(map.len(), map.first().map_or(0, |row| row.len()), 10);
+    let mut antennas: Vec<(Point, &String)> = Vec::new();
+    let mut frequencies: Vec<&String> = Vec::new();
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            let antenna = &map[i][j];
+
+            if *antenna != *"." && *antenna != *"#" {
+                antennas.push((Point::from_index((i, j)), antenna));
+                if !frequencies.contains(&antenna) {
+                    frequencies.push(antenna);
+                }
+            }
+        }
+    }
+
+    for (a, (first, frequency)) in antennas.iter().enumerate() {
+        let colour = hue(frequencies.iter().position(|known| known == frequency).unwrap_or(0));
+
+        for (second, _) in antennas[a + 1..].iter().filter(|(_, other)| other == frequency) {
+            let step = (*second - *first).reduced();
+            let (mut start, mut end) = (*first, *first);
+
+            while (start - step).get(map).is_some() {
+                start -= step;
+            }
+            while (end + step).get(map).is_some() {
+                end += step;
+            }
+
+            svg.polyline(&[start, end], &colour, 1.0);
+        }
+    }
+
+    for (antenna, frequency) in antennas {
+        svg.label(antenna, frequency, "black");
+    }
+
+    return svg;
+}
+
+const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// a size x size roof with three or four antennas for each frequency in use, about one frequency for
+// every row and a half like the real map, antennas never share a spot
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut roof: Vec<Vec<char>> = vec![vec!['.'; size]; size];
+    let mut free: Vec<(usize, usize)> = (0..size * size).map(|spot| (spot / size, spot % size)).collect();
+    rng.shuffle(&mut free);
+
+    for frequency in FREQUENCIES.chars().take(size * 2 / 3) {
+        for _ in 0..rng.range(3..=4) {
+            if let Some((row, col)) = free.pop() {
+                roof[row][col] = frequency;
+            }
+        }
+    }
+
+    return roof
+        .iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect();
+}
+
+// antennas are letters and digits on a rectangle of empty ground
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let input = Input::read(path)?;
+    return Ok([
+        input.rectangular(),
+        input.only(|cell| cell == '.' || cell.is_ascii_alphanumeric()),
+    ]
+    .concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(50)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let map = match phase!("parse", get_map("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve map. {}", e);
+            return;
+        }
+    };
+
+    if !utils::svg::save_requested(|| get_antenna_svg(&map)) {
+        return;
+    }
+
+    let count_of_antinodes = phase!("part one", get_count_of_all_antinodes(&map));
+    println!("total antinodes: {}", count_of_antinodes);
+
+    let count_of_antinodes_in_line = phase!("part two", get_count_of_all_antinodes_in_line(copy_map(&map)));
+    println!("total antinodes in line: {}", count_of_antinodes_in_line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_get_count_of_all_antinodes() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let count = get_count_of_all_antinodes(&map);
+        assert_eq!(count, 14);
+    }
+
+    #[test]
+    fn test_get_count_of_all_antinodes_in_line() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let count = get_count_of_all_antinodes_in_line(copy_map(&map));
+        assert_eq!(count, 34);
+    }
+
+    #[test]
+    fn test_get_antenna_svg() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_get_antenna_svg. {}", e);
+            }
+        };
+
+        let svg = get_antenna_svg(&map).render();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"120\" height=\"120\""));
+        // six pairs of 0s and three of As
+        assert_eq!(svg.matches("<polyline").count(), 9);
+        assert_eq!(svg.matches(">0</text>").count(), 4);
+        assert_eq!(svg.matches(">A</text>").count(), 3);
+
+        assert_eq!(get_antenna_svg(&[]).render(), Svg::new(0, 0, 10).render());
+    }
+
+    #[test]
+    fn test_generate() {
+        let map = match read_back(
+            "y2024-day-08",
+            &[("input.csv", generate(&mut Rng::new(1), 20))],
+            get_map,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_generate. {}", e);
+            }
+        };
+
+        let antennas = map.iter().flatten().filter(|spot| **spot != *".").count();
+        assert!(map.len() == 20 && map.iter().all(|row| row.len() == 20));
+        assert!((13 * 3..=13 * 4).contains(&antennas));
+        let count_of_antinodes = get_count_of_all_antinodes(&map);
+        assert!(0 < count_of_antinodes && count_of_antinodes < get_count_of_all_antinodes_in_line(map));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-08-broken",
+            &[("input.csv", "....\n.a..\n.#..\n...\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(4), "row is 3 wide but the first is 4".to_string()),
+                (Some(3), "unexpected '#' in column 2".to_string())
+            ]
+        );
+    }
+}
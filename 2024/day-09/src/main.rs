@@ -0,0 +1,483 @@
+// https://adventofcode.com/2024/day/9
+
+use rayon::prelude::*;
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::ranges::RangeSet;
+use utils::sim::{Cell, CellKind, Frame, Simulation};
+use utils::validate::{Input, Problem};
+use utils::variant::Registry;
+use utils::{counter, phase};
+
+fn disk_from_disk_map(disk_map: &[String]) -> Vec<String> {
+    let mut disk: Vec<String> = Vec::new();
+
+    for i in 0..disk_map.len() {
+        let digit: u32 = disk_map[i].parse::<u32>().unwrap_or(0);
+
+        for _ in 0..digit {
+            if (i - 1) % 2 == 0 {
+                disk.push(format!("{}", (i - 1) / 2));
+            } else {
+                disk.push(".".to_string());
+            }
+        }
+    }
+
+    return disk;
+}
+
+fn compress_disk(mut disk: Vec<String>) -> Vec<String> {
+    let mut available_spots: Vec<usize> = Vec::new();
+
+    for i in 0..disk.len() {
+        if disk[i] == *"." {
+            available_spots.push(i);
+        }
+    }
+
+    for i in (0..disk.len()).rev() {
+        if disk[i] != *"." {
+            if let Some(j) = available_spots
+                .par_iter()
+                .enumerate()
+                .filter(|&(_, &spot)| spot < i)
+                .min_by_key(|&(_, &spot)| spot)
+                .map(|(index, _)| index)
+            {
+                disk[available_spots[j]] = disk[i].clone();
+                disk[i] = ".".to_string();
+
+                available_spots[j] = i;
+            }
+        }
+    }
+
+    return disk;
+}
+
+fn compress_disk_in_blocks(mut disk: Vec<String>) -> Vec<String> {
+    let mut free_space: RangeSet<usize> = RangeSet::new();
+    let mut blocks_moved: usize = 0;
+
+    for i in 0..disk.len() {
+        if disk[i] == *"." {
+            free_space.insert(i..i + 1);
+        }
+    }
+
+    let mut i: usize = disk.len() - 1;
+    while i > 0 {
+        if disk[i] != *"." {
+            let mut block_length: usize = 0;
+
+            while i >= block_length && disk[i - block_length] == disk[i] {
+                block_length += 1;
+            }
+
+            // the whole file has to fit somewhere before where it starts now
+            let block_start = i + 1 - block_length;
+            if let Some(new_start) = free_space.first_fit(block_length, block_start) {
+                for k in 0..block_length {
+                    disk[new_start + k] = disk[block_start + k].clone();
+                    disk[block_start + k] = ".".to_string();
+                }
+
+                free_space.remove(new_start..new_start + block_length);
+                free_space.insert(block_start..block_start + block_length);
+                blocks_moved += 1;
+            }
+
+            if i > block_length {
+                i -= block_length;
+            } else {
+                i = 0;
+            }
+        } else {
+            i -= 1;
+        }
+    }
+
+    counter!("blocks moved", blocks_moved);
+    return disk;
+}
+
+fn get_cheksum_of_disk(disk: Vec<String>) -> u64 {
+    let mut checksum: u64 = 0;
+
+    for i in 0..disk.len() {
+        if disk[i] != *"." {
+            checksum += (i as u64) * disk[i].parse::<u64>().unwrap_or(0);
+        }
+    }
+
+    return checksum;
+}
+
+// the reference, looks for space by walking the disk from the left for every file
+fn compress_disk_in_blocks_by_scan(mut disk: Vec<String>) -> Vec<String> {
+    let files = disk
+        .iter()
+        .filter_map(|block| block.parse::<usize>().ok())
+        .max()
+        .unwrap_or(0);
+
+    for file in (0..=files).rev() {
+        let id = file.to_string();
+        let (start, length) = match disk.iter().position(|block| *block == id) {
+            Some(start) => (start, disk[start..].iter().take_while(|block| **block == id).count()),
+            None => continue,
+        };
+
+        let mut free: usize = 0;
+        for i in 0..start {
+            free = if disk[i] == *"." { free + 1 } else { 0 };
+
+            if free == length {
+                for k in 0..length {
+                    disk.swap(i + 1 - length + k, start + k);
+                }
+                break;
+            }
+        }
+    }
+
+    return disk;
+}
+
+// part two a file at a time, the disk is wrapped onto rows of width blocks so it fits on screen
+pub struct Compaction {
+    disk: Vec<String>,
+    width: usize,
+    free_space: RangeSet<usize>,
+    // (start, length) of every file by id
+    files: Vec<(usize, usize)>,
+    // the next file to try, files are moved from the highest id down
+    next: Option<usize>,
+    moved: Option<(usize, usize)>,
+}
+
+impl Compaction {
+    pub fn new(disk: &[String], width: usize) -> Compaction {
+        let mut free_space: RangeSet<usize> = RangeSet::new();
+        let mut files: Vec<(usize, usize)> = Vec::new();
+
+        for (i, block) in disk.iter().enumerate() {
+            match block.parse::<usize>() {
+                Ok(id) => {
+                    // a file of no blocks still has an id
+                    if id >= files.len() {
+                        files.resize(id + 1, (i, 0));
+                    }
+                    files[id].1 += 1;
+                }
+                Err(_) => free_space.insert(i..i + 1),
+            }
+        }
+
+        return Compaction {
+            disk: disk.to_owned(),
+            width: width.max(1),
+            free_space,
+            next: files.len().checked_sub(1),
+            files,
+            moved: None,
+        };
+    }
+}
+
+impl Simulation for Compaction {
+    fn step(&mut self) {
+        let id = match self.next {
+            Some(id) => id,
+            None => return,
+        };
+        let (start, length) = self.files[id];
+
+        self.moved = None;
+        if let Some(new_start) = self.free_space.first_fit(length, start) {
+            for k in 0..length {
+                self.disk.swap(new_start + k, start + k);
+            }
+
+            self.free_space.remove(new_start..new_start + length);
+            self.free_space.insert(start..start + length);
+            self.files[id].0 = new_start;
+            self.moved = Some((new_start, length));
+        }
+
+        self.next = id.checked_sub(1);
+    }
+
+    fn is_done(&self) -> bool {
+        return self.next.is_none();
+    }
+
+    // files show the last digit of their id, the one that just moved is highlighted
+    fn render(&self) -> Frame {
+        return self
+            .disk
+            .chunks(self.width)
+            .enumerate()
+            .map(|(row, blocks)| {
+                blocks
+                    .iter()
+                    .enumerate()
+                    .map(|(column, block)| {
+                        let i = row * self.width + column;
+                        match (block.chars().last(), self.moved) {
+                            (Some('.') | None, _) => Cell::new('.', CellKind::Empty),
+                            (Some(digit), Some((start, length))) if (start..start + length).contains(&i) => {
+                                Cell::new(digit, CellKind::Actor)
+                            }
+                            (Some(digit), _) => Cell::new(digit, CellKind::Item),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    fn caption(&self) -> String {
+        return match self.next {
+            Some(id) => format!("file {} next", id),
+            None => "done".to_string(),
+        };
+    }
+}
+
+// the scan is the reference the free space ranges are checked against
+pub fn get_implementations(disk_map: &[String]) -> Registry<'_> {
+    return Registry::new()
+        .add(1, "fast", || {
+            get_cheksum_of_disk(compress_disk(disk_from_disk_map(disk_map)))
+        })
+        .add(2, "fast", || {
+            get_cheksum_of_disk(compress_disk_in_blocks(disk_from_disk_map(disk_map)))
+        })
+        .add(2, "scan", || {
+            get_cheksum_of_disk(compress_disk_in_blocks_by_scan(disk_from_disk_map(disk_map)))
+        });
+}
+
+// a disk map of size digits (one more if that would end on a gap), files take 1 to 9 blocks and
+// the gaps between them 0 to 9
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let digits: Vec<String> = (0..size.max(1) | 1)
+        .map(|i| if i % 2 == 0 { rng.range(1..=9) } else { rng.range(0..=9) }.to_string())
+        .collect();
+
+    return format!("{}\n", digits.concat());
+}
+
+// the disk map is one line of digits, anything else is read as a zero
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    let input = Input::read(path)?;
+    return Ok([input.single_line(), input.only(|digit| digit.is_ascii_digit())].concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(19999)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let mut input: Vec<String> = match phase!("parse", get_csv_data("data/input.csv", false)) {
+        Ok(result) => result[0].clone(),
+        Err(e) => {
+            println!("Error: Failed to retrieve disk map. {}", e);
+            return;
+        }
+    };
+
+    input = input[0].split("").map(String::from).collect();
+    if utils::sim::requested() {
+        if let Err(e) = utils::sim::play(&mut Compaction::new(&disk_from_disk_map(&input), 100)) {
+            println!("Error: Failed to play the simulation. {}", e);
+        }
+        return;
+    }
+
+    if let Some(selected) = utils::variant::requested() {
+        if let Err(e) = utils::variant::compare(&get_implementations(&input), &selected) {
+            println!("Error: Failed to compare implementations. {}", e);
+        }
+        return;
+    }
+
+    let checksum_of_disk = phase!(
+        "part one",
+        get_cheksum_of_disk(compress_disk(disk_from_disk_map(&input)))
+    );
+    println!("checksum of disk: {:?}", checksum_of_disk);
+
+    let checksum_of_disk_in_blocks = phase!(
+        "part two",
+        get_cheksum_of_disk(compress_disk_in_blocks(disk_from_disk_map(&input)))
+    );
+    println!(
+        "checksum of disk (compressed in blocks): {:?}",
+        checksum_of_disk_in_blocks
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::check::proptest::prelude::*;
+    use utils::check::{agree, digits};
+    use utils::generate::{read_back, Rng};
+    use utils::validate::problems_in;
+    use utils::variant::agreed;
+
+    #[test]
+    fn test_get_cheksum_of_disk() {
+        let mut input: Vec<String> = match get_csv_data("data/test.csv", false) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                println!("Error: Failed to retrieve disk map. {}", e);
+                return;
+            }
+        };
+
+        input = input[0].split("").map(String::from).collect();
+        let result = get_cheksum_of_disk(compress_disk(disk_from_disk_map(&input)));
+
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn test_get_cheksum_of_disk_in_blocks() {
+        let mut input: Vec<String> = match get_csv_data("data/test.csv", false) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                println!("Error: Failed to retrieve disk map. {}", e);
+                return;
+            }
+        };
+
+        input = input[0].split("").map(String::from).collect();
+        let result = get_cheksum_of_disk(compress_disk_in_blocks(disk_from_disk_map(&input)));
+
+        assert_eq!(result, 2858);
+    }
+
+    #[test]
+    fn test_compaction_frames() {
+        let mut input: Vec<String> = match get_csv_data("data/test.csv", false) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                panic!("Error: Failed to retrieve disk map in test_compaction_frames. {}", e);
+            }
+        };
+
+        input = input[0].split("").map(String::from).collect();
+        let frames = utils::sim::headless(&mut Compaction::new(&disk_from_disk_map(&input), 21), 100);
+
+        assert_eq!(frames.len(), 11);
+        assert_eq!(frames[0], "00...111...2...333.44\n.5555.6666.777.888899");
+        assert_eq!(frames[1], "0099.111...2...333.44\n.5555.6666.777.8888..");
+        assert_eq!(frames[10], "00992111777.44.333...\n.5555.6666.....8888..");
+    }
+
+    #[test]
+    fn test_blocks_match_scan() {
+        // files take at least one block, the gaps after them can be empty
+        let disk_map = (digits(1..=10, 1..=9), digits(10..=10, 0..=9)).prop_map(|(files, gaps)| {
+            let mut disk_map: Vec<String> = vec![String::new()];
+            for (file, gap) in files.chars().zip(gaps.chars()) {
+                disk_map.push(file.to_string());
+                disk_map.push(gap.to_string());
+            }
+            disk_map
+        });
+
+        if let Err(e) = agree(
+            disk_map,
+            |disk_map| compress_disk_in_blocks_by_scan(disk_from_disk_map(disk_map)),
+            |disk_map| compress_disk_in_blocks(disk_from_disk_map(disk_map)),
+        ) {
+            panic!("Error: Failed to match the scan in test_blocks_match_scan. {}", e);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let input: Vec<String> = match read_back(
+            "y2024-day-09",
+            &[("input.csv", generate(&mut Rng::new(1), 100))],
+            |path| get_csv_data::<String>(path, false),
+        ) {
+            Ok(result) => result[0][0].split("").map(String::from).collect(),
+            Err(e) => {
+                panic!("Error: Failed to retrieve disk map in test_generate. {}", e);
+            }
+        };
+
+        let disk = disk_from_disk_map(&input);
+        assert_eq!(input.len(), 101 + 2);
+        assert_eq!(
+            disk.iter().filter(|block| **block == *"50").count(),
+            input[101].parse().unwrap_or(0)
+        );
+        assert!(get_cheksum_of_disk(compress_disk(disk.clone())) != get_cheksum_of_disk(disk));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-09-broken",
+            &[("input.csv", "2333a31\n12\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "expected one line but found 2".to_string()),
+                (Some(1), "unexpected 'a' in column 5".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        let input: Vec<String> = match get_csv_data::<String>("data/test.csv", false) {
+            Ok(result) => result[0][0].split("").map(String::from).collect(),
+            Err(e) => {
+                panic!(
+                    "Error: Failed to retrieve disk map in test_implementations_agree. {}",
+                    e
+                );
+            }
+        };
+
+        let outcomes = match get_implementations(&input).run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run the implementations. {}", e);
+            }
+        };
+        assert_eq!(
+            agreed(&outcomes),
+            Ok(vec![(1, "1928".to_string()), (2, "2858".to_string())])
+        );
+    }
+}
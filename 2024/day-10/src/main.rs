@@ -0,0 +1,285 @@
+// https://adventofcode.com/2024/day/10
+
+use std::error::Error;
+
+use utils::bits::BitGrid;
+use utils::generate::Rng;
+use utils::geom::Point;
+use utils::get_csv_data;
+use utils::graph::{count_paths, dfs};
+use utils::phase;
+use utils::svg::Svg;
+use utils::validate::{Input, Problem};
+
+pub fn get_map(path: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let mut map: Vec<Vec<String>> = get_csv_data(path, false)?;
+
+    for i in 0..map.len() {
+        let mut row: Vec<String> = map[i][0].split("").map(String::from).collect();
+        if row.first() == Some(&String::from("")) {
+            row.remove(0);
+        }
+
+        if row.last() == Some(&String::from("")) {
+            row.pop();
+        }
+
+        map[i] = row;
+    }
+
+    return Ok(map);
+}
+
+fn get_height(map: &[Vec<String>], (i, j): (usize, usize)) -> Option<u32> {
+    return map
+        .get(i)
+        .and_then(|row| row.get(j))
+        .and_then(|cell| cell.parse::<u32>().ok());
+}
+
+// a trail only ever climbs by exactly one
+fn get_next_steps(map: &[Vec<String>], (i, j): (usize, usize)) -> Vec<(usize, usize)> {
+    let height = match get_height(map, (i, j)) {
+        Some(height) => height,
+        None => return Vec::new(),
+    };
+
+    return [(i.wrapping_sub(1), j), (i + 1, j), (i, j.wrapping_sub(1)), (i, j + 1)]
+        .into_iter()
+        .filter(|spot| get_height(map, *spot) == Some(height + 1))
+        .collect();
+}
+
+fn get_reachable_peaks(map: &[Vec<String>], start: (usize, usize)) -> u32 {
+    return dfs(start, |spot| get_next_steps(map, *spot))
+        .iter()
+        .filter(|spot| get_height(map, **spot) == Some(9))
+        .count() as u32;
+}
+
+pub fn get_count_of_all_paths(map: &[Vec<String>], count_mutiple_routes: bool) -> u32 {
+    let mut count: u32 = 0;
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            if map[i][j] == *"0" {
+                if count_mutiple_routes {
+                    let next_steps = |spot: &(usize, usize)| get_next_steps(map, *spot);
+                    let is_peak = |spot: &(usize, usize)| get_height(map, *spot) == Some(9);
+                    count += count_paths((i, j), next_steps, is_peak) as u32;
+                } else {
+                    count += get_reachable_peaks(map, (i, j));
+                }
+            }
+        }
+    }
+
+    return count;
+}
+
+// the map shaded by height with every step of every trail drawn over it
+pub fn get_trail_svg(map: &[Vec<String>]) -> Svg {
+    let cols = map.first().map_or(0, |row| row.len());
+    let mut svg = Svg::new(map.len(), cols, 10);
+    let mut seen = BitGrid::new(map.len(), cols);
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            if let Some(height) = get_height(map, (i, j)) {
+                svg.fill(
+                    Point::from_index((i, j)),
+                    &format!("hsl(100, 30%, {}%)", 10 + height * 7),
+                );
+            }
+
+            if map[i][j] == *"0" {
+                seen.insert((i, j));
+                stack.push((i, j));
+            }
+        }
+    }
+
+    while let Some(spot) = stack.pop() {
+        for next in get_next_steps(map, spot) {
+            svg.polyline(&[Point::from_index(spot), Point::from_index(next)], "orange", 2.0);
+
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    return svg;
+}
+
+// a size x size map of random heights with trails climbing 0 to 9 walked over it one step at a
+// time, a trail that runs into the edge or back into itself stops short
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut map: Vec<Vec<i64>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.range(0..=9)).collect())
+        .collect();
+
+    for _ in 0..size * size / 8 {
+        let mut spot = Point::from_index((rng.below(size), rng.below(size)));
+        let mut trail: Vec<Point> = Vec::new();
+
+        for height in 0..=9 {
+            if let Some(cell) = spot.get_mut(&mut map) {
+                *cell = height;
+            }
+            trail.push(spot);
+
+            let next: Vec<Point> = spot
+                .neighbours()
+                .into_iter()
+                .filter(|next| next.get(&map).is_some() && !trail.contains(next))
+                .collect();
+            match rng.pick(&next) {
+                Some(next) => spot = *next,
+                None => break,
+            }
+        }
+    }
+
+    return map
+        .iter()
+        .map(|row| format!("{}\n", row.iter().map(|height| height.to_string()).collect::<String>()))
+        .collect();
+}
+
+// a rectangle of heights, a cell that is not a digit can never be part of a trail
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let input = Input::read(path)?;
+    return Ok([input.rectangular(), input.only(|height| height.is_ascii_digit())].concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(55)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let map = match phase!("parse", get_map("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve map. {}", e);
+            return;
+        }
+    };
+
+    if !utils::svg::save_requested(|| get_trail_svg(&map)) {
+        return;
+    }
+
+    let count_of_all_unique_paths = phase!("part one", get_count_of_all_paths(&map, false));
+    println!("all unique paths: {}", count_of_all_unique_paths);
+
+    let count_of_all_possible_paths = phase!("part two", get_count_of_all_paths(&map, true));
+    println!("all possible paths: {}", count_of_all_possible_paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_get_count_of_all_unique_paths() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let result = get_count_of_all_paths(&map, false);
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn test_get_count_of_all_possible_paths() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let result = get_count_of_all_paths(&map, true);
+        assert_eq!(result, 81);
+    }
+
+    #[test]
+    fn test_get_trail_svg() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_get_trail_svg. {}", e);
+            }
+        };
+
+        let svg = get_trail_svg(&map).render();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"80\" height=\"80\""));
+        assert_eq!(svg.matches("<rect").count(), 64);
+        // the trailhead in the top row climbs to its right
+        assert!(svg.contains("points=\"25,5 35,5\""));
+        assert!(!svg.contains("points=\"5,5 "));
+
+        assert_eq!(get_trail_svg(&[]).render(), Svg::new(0, 0, 10).render());
+    }
+
+    #[test]
+    fn test_generate() {
+        let map = match read_back(
+            "y2024-day-10",
+            &[("input.csv", generate(&mut Rng::new(1), 30))],
+            get_map,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_generate. {}", e);
+            }
+        };
+
+        let peaks = get_count_of_all_paths(&map, false);
+        assert!(map.len() == 30 && map.iter().all(|row| row.len() == 30));
+        assert!(0 < peaks && peaks <= get_count_of_all_paths(&map, true));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-10-broken",
+            &[("input.csv", "0123\n1234\n8765\n9.76\n1\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(5), "row is 1 wide but the first is 4".to_string()),
+                (Some(4), "unexpected '.' in column 2".to_string())
+            ]
+        );
+    }
+}
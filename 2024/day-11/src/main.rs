@@ -0,0 +1,174 @@
+// https://adventofcode.com/2024/day/11
+
+use utils::generate::Rng;
+use utils::get_csv_data;
+use utils::memo::Memo;
+use utils::validate::{columns, Input, Problem};
+use utils::{counter, phase};
+
+fn apply_change_rules(rock_value: u64) -> Vec<u64> {
+    let rock_string = rock_value.to_string();
+
+    if rock_value == 0 {
+        return vec![1];
+    }
+
+    if rock_string.len().is_multiple_of(2) {
+        let (left, right) = rock_string.split_at(rock_string.len() / 2);
+        return vec![left.parse::<u64>().unwrap_or(0), right.parse::<u64>().unwrap_or(0)];
+    }
+
+    return vec![rock_value * 2024];
+}
+
+// rocks never affect each other, so a rock and the blinks left is all that decides its count
+fn count_rocks(recurse: &mut dyn FnMut((u64, u32)) -> u64, (rock_value, blinks): (u64, u32)) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+
+    return apply_change_rules(rock_value)
+        .into_iter()
+        .map(|rock| recurse((rock, blinks - 1)))
+        .sum();
+}
+
+pub fn get_count_of_rocks_after_blinks(rocks: Vec<u64>, blinks: u32) -> u64 {
+    let mut memo: Memo<(u64, u32), u64> = Memo::new();
+    let count = rocks
+        .into_iter()
+        .map(|rock| memo.get((rock, blinks), &count_rocks))
+        .sum();
+
+    counter!("memo hits", memo.stats().hits);
+    counter!("memo misses", memo.stats().misses);
+
+    return count;
+}
+
+// a row of size stones with anything from one to seven digits engraved, 0 turns up as often as any
+// other length since it has its own rule
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rocks: Vec<String> = (0..size)
+        .map(|_| match rng.range(0..=7) {
+            0 => 0,
+            digits => rng.range(10i64.pow(digits as u32 - 1)..=10i64.pow(digits as u32) - 1),
+        })
+        .map(|rock| rock.to_string())
+        .collect();
+
+    return format!("{}\n", rocks.join(","));
+}
+
+// one line of stones, only the first line is ever read
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn std::error::Error>> {
+    let input = Input::read(path)?;
+    return Ok([input.single_line(), input.each(columns::<u64>)].concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(8)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let rocks: Vec<u64> = match phase!("parse", get_csv_data("data/input.csv", false)) {
+        Ok(result) => result[0].clone(),
+        Err(e) => {
+            println!("Error: Failed to retrieve rocks. {}", e);
+            return;
+        }
+    };
+
+    let blinks_25 = phase!("part one", get_count_of_rocks_after_blinks(rocks.clone(), 25));
+    println!("after 25 blinks: {}", blinks_25);
+
+    let blinks_75 = phase!("part two", get_count_of_rocks_after_blinks(rocks, 75));
+    println!("after 75 blinks: {}", blinks_75);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_get_count_of_rocks_after_25_blinks() {
+        let rocks: Vec<u64> = match get_csv_data("data/test.csv", false) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                println!("Error: Failed to retrieve rocks. {}", e);
+                return;
+            }
+        };
+
+        let result = get_count_of_rocks_after_blinks(rocks, 25);
+        assert_eq!(result, 55312);
+    }
+
+    #[test]
+    fn test_get_count_of_rocks_after_75_blinks() {
+        let rocks: Vec<u64> = match get_csv_data("data/test.csv", false) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                println!("Error: Failed to retrieve rocks. {}", e);
+                return;
+            }
+        };
+
+        let result = get_count_of_rocks_after_blinks(rocks, 75);
+        assert_eq!(result, 65601038650482);
+    }
+
+    #[test]
+    fn test_generate() {
+        let rocks = match read_back(
+            "y2024-day-11",
+            &[("input.csv", generate(&mut Rng::new(1), 20))],
+            |path| get_csv_data::<u64>(path, false),
+        ) {
+            Ok(result) => result[0].clone(),
+            Err(e) => {
+                panic!("Error: Failed to retrieve rocks in test_generate. {}", e);
+            }
+        };
+
+        assert_eq!(rocks.len(), 20);
+        assert!(rocks.iter().all(|rock| *rock < 10_000_000));
+        assert!(get_count_of_rocks_after_blinks(rocks, 25) > 20);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-11-broken",
+            &[("input.csv", "125,17\n0,x\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "expected one line but found 2".to_string()),
+                (Some(2), "column 2 (\"x\") is not a number".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,603 @@
+// https://adventofcode.com/2024/day/12
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use utils::dsu::{label_grid, Components};
+use utils::generate::Rng;
+use utils::geom::Point;
+use utils::get_csv_data;
+use utils::svg::{hue, Svg};
+use utils::validate::{Input, Problem};
+use utils::variant::Registry;
+use utils::{counter, phase, step};
+
+pub fn get_map(path: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let mut map: Vec<Vec<String>> = get_csv_data(path, false)?;
+    let mut border: Vec<String> = Vec::new();
+
+    let row_len: usize = map[0][0].split("").map(String::from).collect::<Vec<String>>().len();
+    for _ in 0..row_len {
+        border.push(".".to_string());
+    }
+
+    for i in 0..map.len() {
+        let mut row: Vec<String> = map[i][0].split("").map(String::from).collect();
+
+        let end = row.len() - 1;
+        row[0] = ".".to_string();
+        row[end] = ".".to_string();
+
+        map[i] = row;
+    }
+
+    map.push(border);
+    map.insert(0, map[map.len() - 1].clone());
+
+    return Ok(map);
+}
+
+fn get_fences(map: &[Vec<String>]) -> Vec<Vec<u32>> {
+    let mut fences: Vec<Vec<u32>> = Vec::new();
+
+    for i in 0..map.len() {
+        let mut row: Vec<u32> = Vec::new();
+
+        for j in 0..map[i].len() {
+            if map[i][j] == *"." {
+                row.push(0);
+                continue;
+            }
+
+            let mut check_spots: Vec<(usize, usize)> = Vec::new();
+            let mut fence_number: u32 = 0;
+
+            if j != 0 {
+                check_spots.push((i, j - 1))
+            }
+
+            if i != 0 {
+                check_spots.push((i - 1, j));
+            }
+
+            if i != map.len() - 1 {
+                check_spots.push((i + 1, j));
+            }
+
+            if j != map[0].len() - 1 {
+                check_spots.push((i, j + 1));
+            }
+
+            fence_number += 4 - check_spots.len() as u32;
+
+            for (spot_i, spot_j) in check_spots {
+                if map[i][j] != map[spot_i][spot_j] {
+                    fence_number += 1;
+                }
+            }
+
+            row.push(fence_number);
+        }
+
+        fences.push(row);
+    }
+
+    return fences;
+}
+// this function only works in the test case
+fn get_sides_by_edges(map: &[Vec<String>]) -> u32 {
+    let mut count: u32 = 0;
+    let mut detecting_edge: bool = false;
+    let mut prev = ".".to_string();
+
+    for i in 0..(map.len() - 1) {
+        for j in 0..map[i].len() {
+            if map[i][j] != map[i + 1][j] {
+                if detecting_edge && map[i][j] != prev {
+                    count += 1;
+                    detecting_edge = false;
+                } else {
+                    detecting_edge = true;
+                    prev = map[i][j].clone();
+                }
+            } else if detecting_edge {
+                count += 1;
+                detecting_edge = false;
+            }
+        }
+    }
+
+    prev = ".".to_string();
+    detecting_edge = false;
+
+    for j in 0..(map.len() - 1) {
+        for i in 0..map[j].len() {
+            if map[i][j] != map[i][j + 1] {
+                if detecting_edge && map[i][j] != prev {
+                    count += 1;
+                    detecting_edge = false;
+                } else {
+                    detecting_edge = true;
+                    prev = map[i][j].clone();
+                }
+            } else if detecting_edge {
+                count += 1;
+                detecting_edge = false;
+            }
+        }
+    }
+
+    return count;
+}
+
+// this is my horrific attempt on solving part 2, there is way to manny variables to ajust
+#[allow(
+    non_snake_case,
+    unused_variables,
+    clippy::nonminimal_bool,
+    clippy::overly_complex_bool_expr
+)]
+fn get_sides_by_patterns(map: &[Vec<String>]) -> u32 {
+    let mut count: u32 = 0;
+
+    for i in 0..(map.len() - 2) {
+        for j in 0..(map[i].len() - 2) {
+            if map[i + 1][j + 1] == *"." {
+                continue;
+            }
+            let corner_a = map[i + 1][j] == map[i + 1][j + 1] && map[i][j + 1] == map[i + 1][j + 1];
+            let corner_b = map[i][j + 1] == map[i + 1][j + 1] && map[i + 1][j + 2] == map[i + 1][j + 1];
+            let corner_c = map[i + 1][j + 2] == map[i + 1][j + 1] && map[i + 2][j + 1] == map[i + 1][j + 1];
+            let corner_d = map[i + 2][j + 1] == map[i + 1][j + 1] && map[i + 1][j] == map[i + 1][j + 1];
+
+            let double_a = corner_a && map[i][j] != map[i + 1][j + 1];
+            let double_b = corner_b && map[i][j + 2] != map[i + 1][j + 1];
+            let double_c = corner_c && map[i + 2][j + 2] != map[i + 1][j + 1];
+            let double_d = corner_d && map[i + 2][j] != map[i + 1][j + 1];
+
+            let kite_a = corner_a
+                && map[i][j] == map[i + 1][j + 1]
+                && map[i + 2][j + 2] == map[i + 1][j + 1]
+                && map[i + 1][j + 2] != map[i + 1][j + 1]
+                && map[i + 2][j + 1] != map[i + 1][j + 1];
+
+            let kite_b = corner_b
+                && map[i][j + 2] == map[i + 1][j + 1]
+                && map[i + 2][j] == map[i + 1][j + 1]
+                && map[i + 1][j] != map[i + 1][j + 1]
+                && map[i + 2][j + 1] != map[i + 1][j + 1];
+
+            let kite_c = corner_c
+                && map[i + 2][j + 2] == map[i + 1][j + 1]
+                && map[i][j] == map[i + 1][j + 1]
+                && map[i + 1][j] != map[i + 1][j + 1]
+                && map[i][j + 1] != map[i + 1][j + 1];
+
+            let kite_d = corner_d
+                && map[i + 2][j] == map[i + 1][j + 1]
+                && map[i][j + 2] == map[i + 1][j + 1]
+                && map[i][j + 1] != map[i + 1][j + 1]
+                && map[i + 1][j + 2] != map[i + 1][j + 1];
+
+            let not_kite_a = corner_a
+                && map[i][j] == map[i + 1][j + 1]
+                && map[i + 2][j + 2] == map[i + 1][j + 1]
+                && (map[i + 1][j + 2] == map[i + 1][j + 1] || map[i + 2][j + 1] == map[i + 1][j + 1]);
+
+            let not_kite_b = corner_b
+                && map[i][j + 2] == map[i + 1][j + 1]
+                && map[i + 2][j] == map[i + 1][j + 1]
+                && (map[i + 1][j] == map[i + 1][j + 1] || map[i + 2][j + 1] == map[i + 1][j + 1]);
+
+            let not_kite_c = corner_c
+                && map[i + 2][j + 2] == map[i + 1][j + 1]
+                && map[i][j] == map[i + 1][j + 1]
+                && (map[i + 1][j] == map[i + 1][j + 1] || map[i][j + 1] == map[i + 1][j + 1]);
+
+            let not_kite_d = corner_d
+                && map[i + 2][j] == map[i + 1][j + 1]
+                && map[i][j + 2] == map[i + 1][j + 1]
+                && (map[i][j + 1] == map[i + 1][j + 1] || map[i + 1][j + 2] == map[i + 1][j + 1]);
+
+            let H_a = map[i][j] == map[i][j + 1] && map[i][j + 2] == map[i][j + 1];
+            let H_b = map[i + 1][j] == map[i + 1][j + 1] && map[i + 1][j + 2] == map[i + 1][j + 1];
+            let H_c = map[i + 2][j] == map[i + 2][j + 1] && map[i + 2][j + 2] == map[i + 2][j + 1];
+
+            let H_not_a = map[i][j] != map[i][j + 1] && map[i][j + 2] != map[i][j + 1];
+            let H_not_b = map[i + 1][j] != map[i + 1][j + 1] && map[i + 1][j + 2] != map[i + 1][j + 1];
+            let H_not_c = map[i + 2][j] != map[i + 1][j + 1] && map[i + 2][j + 2] != map[i + 1][j + 1];
+
+            let V_a = map[i][j] == map[i + 1][j] && map[i + 2][j] == map[i + 1][j];
+            let V_b = map[i][j + 1] == map[i + 1][j + 1] && map[i + 2][j + 1] == map[i + 1][j + 1];
+            let V_c = map[i][j + 2] == map[i + 1][j + 2] && map[i + 2][j + 2] == map[i + 1][j + 2];
+
+            let V_not_a = map[i][j] != map[i + 1][j] && map[i + 2][j] != map[i + 1][j];
+            let V_not_b = map[i][j + 1] != map[i + 1][j + 1] && map[i + 2][j + 1] != map[i + 1][j + 1];
+            let V_not_c = map[i][j + 2] != map[i + 1][j + 1] && map[i + 2][j + 2] != map[i + 1][j + 1];
+
+            let is_wall = (H_a && H_b) || (H_c && H_b) || (V_a && V_b) || (V_c && V_b);
+
+            let is_corner = corner_a || corner_b || corner_c || corner_d;
+            let is_double = double_a || double_b || double_c || double_d;
+
+            let is_kite = kite_a || kite_b || kite_c || kite_d;
+            let is_not_kite = not_kite_a || not_kite_b || not_kite_c || not_kite_d;
+
+            let is_dot = (H_a && !H_b && H_c) && (V_a && !V_b && V_c);
+
+            let is_nub = (H_a && !H_b && H_c)
+                || (H_a && !H_b && !H_c)
+                || (!H_a && !H_b && H_c)
+                || (V_a && !V_b && V_c)
+                || (V_a && !V_b && !V_c)
+                || (!V_a && !V_b && V_c);
+
+            let is_h = (V_a && V_not_b && V_c) || (H_a && H_not_b && H_c);
+
+            let is_plus = V_b && H_b && H_not_a && H_not_c && V_not_a && V_not_c;
+
+            let is_t = (V_a && H_b && V_not_b && V_not_c)
+                || (V_c && H_b && V_not_b && V_not_c)
+                || (H_a && V_b && H_not_b && H_not_c)
+                || (H_c && V_b && H_not_b && H_not_c);
+
+            let is_not_t = (V_a && H_b && (!V_not_b || !V_not_c))
+                || (V_c && H_b && (!V_not_b || !V_not_c))
+                || (H_a && V_b && (!H_not_b || !H_not_c))
+                || (H_c && V_b && (!H_not_b || !H_not_c));
+
+            let is_inv_corner = (H_a && H_b && V_b && V_c)
+                || (H_a && H_b && V_a && V_b)
+                || (H_b && H_c && V_b && V_c)
+                || (H_b && H_c && V_a && V_b);
+
+            //if is_wall && is_corner { continue; }
+
+            if is_dot {
+                count += 4;
+                println!("dot");
+            } else if is_kite {
+                count += 3;
+                println!("kite");
+            } else if is_t {
+                count += 2;
+            } else if is_double && !is_not_t && !is_not_kite && !is_plus {
+                count += 2;
+                println!("double");
+            } else if is_corner && !is_wall /*&& !is_not_t*/ && !is_not_kite && !is_plus {
+                count += 1;
+                println!("corner");
+            } else if is_inv_corner {
+                count += 1;
+                println!("inv corner");
+            } else if is_nub && !is_not_t {
+                count += 2;
+                println!("nub");
+            } else if is_h {
+                count += 3;
+                println!("h");
+            } else { /*continue;*/
+            }
+
+            println!("{}, {}, {}", map[i][j], map[i][j + 1], map[i][j + 2]);
+            println!("{}, {}, {}", map[i + 1][j], map[i + 1][j + 1], map[i + 1][j + 2]);
+            println!("{}, {}, {}", map[i + 2][j], map[i + 2][j + 1], map[i + 2][j + 2]);
+            println!("---");
+        }
+    }
+
+    return count;
+}
+
+pub fn get_unique_regions(map: &[Vec<String>]) -> Components {
+    let regions = step!("union find", label_grid(map, |a, b| a == b));
+
+    // the "." border is a region of its own, only labels with a plant in them count
+    counter!(
+        "regions found",
+        regions
+            .labels
+            .iter()
+            .zip(map)
+            .flat_map(|(labels, row)| labels.iter().zip(row).filter(|(_, cell)| *cell != "."))
+            .map(|(label, _)| *label)
+            .collect::<HashSet<usize>>()
+            .len()
+    );
+    return regions;
+}
+
+pub fn get_cost_of_fencing(map: &[Vec<String>], regions: &Components) -> u32 {
+    let fences: Vec<Vec<u32>> = get_fences(map);
+    let mut perimeters: Vec<u32> = vec![0; regions.sizes.len()];
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            perimeters[regions.labels[i][j]] += fences[i][j];
+        }
+    }
+
+    return perimeters
+        .iter()
+        .zip(&regions.sizes)
+        .map(|(perimeter, &size)| perimeter * size as u32)
+        .sum();
+}
+
+// every region in its own colour with its crop on its first plot and a fence wherever two
+// regions meet
+pub fn get_fence_svg(map: &[Vec<String>], regions: &Components) -> Svg {
+    let mut svg = Svg::new(map.len(), map.first().map_or(0, |row| row.len()), 12);
+    let mut labelled: Vec<bool> = vec![false; regions.sizes.len()];
+    let mut labels: Vec<(Point, &String)> = Vec::new();
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            let label = regions.labels[i][j];
+            if map[i][j] == *"." {
+                continue;
+            }
+
+            svg.fill(Point::from_index((i, j)), &hue(label));
+            if !labelled[label] {
+                labelled[label] = true;
+                labels.push((Point::from_index((i, j)), &map[i][j]));
+            }
+        }
+    }
+
+    for i in 0..map.len() {
+        for j in 0..map[i].len() {
+            for (next_i, next_j) in [(i + 1, j), (i, j + 1)] {
+                let next = regions.labels.get(next_i).and_then(|row| row.get(next_j));
+
+                if next.is_some_and(|next| *next != regions.labels[i][j]) {
+                    svg.edge(
+                        Point::from_index((i, j)),
+                        Point::from_index((next_i, next_j)),
+                        "black",
+                        2.0,
+                    );
+                }
+            }
+        }
+    }
+
+    for (cell, crop) in labels {
+        svg.label(cell, crop, "black");
+    }
+
+    return svg;
+}
+
+// a size x size garden grown out from one seed plot for every thirty or so, each seed picks a
+// random crop and grows into whichever neighbour comes up next, so regions come out ragged and
+// neighbouring ones sometimes share a crop and merge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let crops: Vec<char> = ('A'..='Z').collect();
+    let mut garden: Vec<Vec<Option<char>>> = vec![vec![None; size]; size];
+    let mut growing: Vec<Point> = Vec::new();
+
+    for _ in 0..(size * size / 30).max(1).min(size * size) {
+        let seed = Point::from_index((rng.below(size), rng.below(size)));
+        if let Some(plot) = seed.get_mut(&mut garden) {
+            *plot = rng.pick(&crops).copied();
+            growing.push(seed);
+        }
+    }
+
+    while !growing.is_empty() {
+        let i = rng.below(growing.len());
+        let crop = growing[i].get(&garden).copied().flatten();
+        let empty: Vec<Point> = growing[i]
+            .neighbours()
+            .into_iter()
+            .filter(|next| next.get(&garden) == Some(&None))
+            .collect();
+
+        match rng.pick(&empty) {
+            Some(next) => {
+                if let Some(plot) = next.get_mut(&mut garden) {
+                    *plot = crop;
+                }
+                growing.push(*next);
+            }
+            None => {
+                growing.swap_remove(i);
+            }
+        }
+    }
+
+    return garden
+        .iter()
+        .map(|row| format!("{}\n", row.iter().map(|plot| plot.unwrap_or('A')).collect::<String>()))
+        .collect();
+}
+
+// get_map pads the garden with '.' so the plants themselves have to be capital letters
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let input = Input::read(path)?;
+    return Ok([input.rectangular(), input.only(|plant| plant.is_ascii_uppercase())].concat());
+}
+
+// part two is not solved yet, the two attempts at counting sides are kept as experiments and give
+// the number of sides on the whole map rather than a cost
+pub fn get_implementations<'a>(map: &'a [Vec<String>], regions: &'a Components) -> Registry<'a> {
+    return Registry::new()
+        .add(1, "fast", move || get_cost_of_fencing(map, regions))
+        .experiment(2, "edges", move || get_sides_by_edges(map))
+        .experiment(2, "patterns", move || get_sides_by_patterns(map));
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(140)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let map = match phase!("parse", get_map("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve map. {}", e);
+            return;
+        }
+    };
+
+    let regions = get_unique_regions(&map);
+
+    if !utils::svg::save_requested(|| get_fence_svg(&map, &regions)) {
+        return;
+    }
+
+    if let Some(selected) = utils::variant::requested() {
+        if let Err(e) = utils::variant::compare(&get_implementations(&map, &regions), &selected) {
+            println!("Error: Failed to compare implementations. {}", e);
+        }
+        return;
+    }
+
+    let cost = phase!("part one", get_cost_of_fencing(&map, &regions));
+    println!("cost: {}", cost);
+    /*
+        let cost_with_discount = get_cost_of_fencing(get_unique_regions(&map), true);
+        println!(
+            "cost with discount (witch aparently is not right): {}",
+            cost_with_discount
+        );
+    */
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+    use utils::variant::agreed;
+
+    #[test]
+    fn test_get_cost_of_fencing() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let cost = get_cost_of_fencing(&map, &get_unique_regions(&map));
+        assert_eq!(cost, 1930);
+    }
+    /*
+        #[test]
+        fn test_get_cost_of_fencing_with_bulk_discount() {
+            let map = match get_map("data/test.csv") {
+                Ok(result) => result,
+                Err(e) => {
+                    println!("Error: Failed to retrieve map. {}", e);
+                    return;
+                }
+            };
+
+            let cost = get_cost_of_fencing(get_unique_regions(&map), true);
+            assert_eq!(cost, 1206);
+        }
+    */
+
+    #[test]
+    fn test_get_fence_svg() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_get_fence_svg. {}", e);
+            }
+        };
+
+        // the map is padded with a border of "." that is left blank
+        let svg = get_fence_svg(&map, &get_unique_regions(&map)).render();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"144\" height=\"144\""));
+        assert_eq!(svg.matches("<rect").count(), 100);
+        assert_eq!(svg.matches("</text>").count(), 11);
+        assert_eq!(svg.matches(">R</text>").count(), 1);
+        assert_eq!(svg.matches(">I</text>").count(), 2);
+
+        let empty: Vec<Vec<String>> = Vec::new();
+        assert_eq!(
+            get_fence_svg(&empty, &get_unique_regions(&empty)).render(),
+            Svg::new(0, 0, 12).render()
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let map = match read_back(
+            "y2024-day-12",
+            &[("input.csv", generate(&mut Rng::new(1), 40))],
+            get_map,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_generate. {}", e);
+            }
+        };
+
+        let regions = get_unique_regions(&map);
+        // get_map pads the garden with a border of .
+        assert!(map.len() == 42 && map.iter().all(|row| row.len() == 42));
+        assert!(regions.sizes.len() > 10);
+        assert!(get_cost_of_fencing(&map, &regions) > 0);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-12-broken",
+            &[("input.csv", "AAAA\nBB.C\nBBCCC\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(3), "row is 5 wide but the first is 4".to_string()),
+                (Some(2), "unexpected '.' in column 3".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        let map = match get_map("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_implementations_agree. {}", e);
+            }
+        };
+
+        let regions = get_unique_regions(&map);
+        let outcomes = match get_implementations(&map, &regions).run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run the implementations. {}", e);
+            }
+        };
+        // the side counts are experiments so only part one is checked
+        assert_eq!(agreed(&outcomes), Ok(vec![(1, "1930".to_string())]));
+        assert_eq!(outcomes.iter().filter(|outcome| outcome.experimental).count(), 2);
+    }
+}
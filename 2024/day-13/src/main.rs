@@ -0,0 +1,413 @@
+// https://adventofcode.com/2024/day/13
+
+use rayon::prelude::*;
+use std::error::Error;
+
+use utils::generate::Rng;
+use utils::math::div_exact;
+use utils::validate::{Input, Problem};
+use utils::variant::Registry;
+use utils::{get_lines, phase, scan};
+
+#[derive(Clone, Debug)]
+pub struct Mechine {
+    button_a: (u64, u64),
+    button_b: (u64, u64),
+    prize: (u64, u64),
+}
+
+const MECHINE_TEMPLATE: &str = "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}";
+
+pub fn get_mechines(path: &str) -> Result<Vec<Mechine>, Box<dyn Error>> {
+    return Ok(get_lines(path)?
+        .chunks(3)
+        .map(|lines| scan!(lines.join("\n"), MECHINE_TEMPLATE, u64, u64, u64, u64, u64, u64))
+        .map(|mechine| {
+            mechine.map(|(a_x, a_y, b_x, b_y, prize_x, prize_y)| Mechine {
+                button_a: (a_x, a_y),
+                button_b: (b_x, b_y),
+                prize: (prize_x, prize_y),
+            })
+        })
+        .collect::<Result<Vec<Mechine>, _>>()?);
+}
+
+pub fn fix_precision_errors(mechines: &Vec<Mechine>) -> Vec<Mechine> {
+    let mut new_mechines: Vec<Mechine> = Vec::new();
+
+    for mechine in mechines {
+        let mut new_mechine = mechine.clone();
+
+        let (mut x, mut y) = new_mechine.prize;
+        x += 10000000000000;
+        y += 10000000000000;
+
+        new_mechine.prize = (x, y);
+        new_mechines.push(new_mechine);
+    }
+
+    return new_mechines;
+}
+
+// the genetic algorithm from the first go at the large prizes, a population of a press counts is
+// sorted by the cost of the b presses that finish each one off and the best tenth breed by nudging
+// their counts, it stops once the best has not changed for 20 generations, only an exact hit counts
+// so it can't settle on the wrong answer but it can miss the right one, so it is registered as an
+// experiment and not checked against the others
+const POPULATION_SIZE: usize = 1000;
+const STAGNATION_LIMIT: usize = 20;
+const GENERATION_LIMIT: usize = 200;
+
+fn fitness(mechine: &Mechine, a: i128, limit: i128) -> u64 {
+    return match get_b_presses(mechine, a) {
+        Some(b) if (0..=limit).contains(&a) && b <= limit => (a * 3 + b) as u64,
+        _ => u64::MAX,
+    };
+}
+
+fn lowest_cost_by_evolving(mechine: &Mechine, limit: u64) -> u64 {
+    let limit = limit as i128;
+    let mut rng = Rng::new(42);
+    let mut population: Vec<i128> = (0..POPULATION_SIZE)
+        .map(|_| rng.range(0..=limit as i64) as i128)
+        .collect();
+
+    let mut best_cost = u64::MAX;
+    let mut stagnation_count = 0;
+
+    for _ in 0..GENERATION_LIMIT {
+        population.par_sort_by_key(|a| fitness(mechine, *a, limit));
+
+        let cost = fitness(mechine, population[0], limit);
+        if cost < best_cost {
+            best_cost = cost;
+            stagnation_count = 0;
+        } else {
+            stagnation_count += 1;
+        }
+
+        if stagnation_count >= STAGNATION_LIMIT {
+            break;
+        }
+
+        // nudge harder while nothing lands on the prize and more gently as the cost comes down
+        let mutation_factor = if cost < 500 {
+            (10 * cost as i64 / 500).max(1)
+        } else {
+            10
+        };
+
+        let elite: Vec<i128> = population[..POPULATION_SIZE / 10].to_vec();
+        population = elite.clone();
+        while population.len() < POPULATION_SIZE {
+            let parent = elite[population.len() % elite.len()];
+            let child = parent + rng.range(-mutation_factor..=mutation_factor) as i128;
+            population.push(child.clamp(0, limit));
+        }
+    }
+
+    if best_cost == u64::MAX {
+        return 0;
+    }
+
+    return best_cost;
+}
+
+// b presses once a presses are known, None when they don't land exactly on the prize
+fn get_b_presses(mechine: &Mechine, a: i128) -> Option<i128> {
+    let (a_x, a_y) = (mechine.button_a.0 as i128, mechine.button_a.1 as i128);
+    let (b_x, b_y) = (mechine.button_b.0 as i128, mechine.button_b.1 as i128);
+    let (left_x, left_y) = (mechine.prize.0 as i128 - a * a_x, mechine.prize.1 as i128 - a * a_y);
+
+    let b = match (b_x, b_y) {
+        (0, 0) => 0,
+        (0, _) => div_exact(left_y, b_y)?,
+        _ => div_exact(left_x, b_x)?,
+    };
+
+    if b >= 0 && b * b_x == left_x && b * b_y == left_y {
+        return Some(b);
+    }
+
+    return None;
+}
+
+// the two buttons are two equations in two unknowns, so unless they point the same way there is
+// only one answer and cramer's rule finds it, buttons that do point the same way fall back to
+// trying each count of a presses
+fn lowest_cost_by_solving(mechine: &Mechine, limit: u64) -> u64 {
+    let (a_x, a_y) = (mechine.button_a.0 as i128, mechine.button_a.1 as i128);
+    let (b_x, b_y) = (mechine.button_b.0 as i128, mechine.button_b.1 as i128);
+    let (prize_x, prize_y) = (mechine.prize.0 as i128, mechine.prize.1 as i128);
+    let limit = limit as i128;
+
+    let determinant = a_x * b_y - a_y * b_x;
+    let presses: Vec<(i128, i128)> = if determinant != 0 {
+        div_exact(prize_x * b_y - prize_y * b_x, determinant)
+            .and_then(|a| Some((a, get_b_presses(mechine, a)?)))
+            .into_iter()
+            .collect()
+    } else {
+        (0..=limit)
+            .filter_map(|a| Some((a, get_b_presses(mechine, a)?)))
+            .collect()
+    };
+
+    return presses
+        .iter()
+        .filter(|(a, b)| (0..=limit).contains(a) && (0..=limit).contains(b))
+        .map(|(a, b)| (a * 3 + b) as u64)
+        .min()
+        .unwrap_or(0);
+}
+
+fn lowest_cost_for_mechine(mechine: &Mechine) -> u64 {
+    return lowest_cost_by_solving(mechine, 100);
+}
+
+pub fn get_cost_for_all_prizes(mechines: &[Mechine]) -> u64 {
+    let mut cost: u64 = 0;
+
+    for mechine in mechines {
+        cost += lowest_cost_for_mechine(mechine);
+    }
+
+    return cost;
+}
+
+fn get_x_and_y(a_x: u64, a_y: u64, b_x: u64, b_y: u64, prize_x: u64, prize_y: u64) -> Option<(u64, u64, u64)> {
+    (0..=100)
+        .into_par_iter()
+        .filter_map(|x| {
+            (0..=100).find_map(|y| {
+                let total_x = x * a_x + y * b_x;
+                let total_y = x * a_y + y * b_y;
+
+                if total_x == prize_x && total_y == prize_y {
+                    let cost = x * 3 + y;
+                    Some((x, y, cost))
+                } else {
+                    None
+                }
+            })
+        })
+        .reduce_with(|a, b| if a.2 < b.2 { a } else { b })
+}
+
+// the reference, tries every way of pressing each button up to 100 times
+fn lowest_cost_by_search(mechine: &Mechine) -> u64 {
+    let (a_x, a_y) = mechine.button_a;
+    let (b_x, b_y) = mechine.button_b;
+    let (prize_x, prize_y) = mechine.prize;
+
+    if let Some((_, _, cost)) = get_x_and_y(a_x, a_y, b_x, b_y, prize_x, prize_y) {
+        cost
+    } else {
+        0
+    }
+}
+
+// the search is the reference the solver is checked against, the genetic algorithm is the old
+// attempt kept as an experiment
+pub fn get_implementations(mechines: &[Mechine]) -> Registry<'_> {
+    return Registry::new()
+        .add(1, "fast", || get_cost_for_all_prizes(mechines))
+        .add(1, "search", || mechines.iter().map(lowest_cost_by_search).sum::<u64>())
+        .experiment(1, "genetic", || {
+            mechines
+                .iter()
+                .map(|mechine| lowest_cost_by_evolving(mechine, 100))
+                .sum::<u64>()
+        });
+}
+
+// size machines with two digit buttons, about two thirds can win their prize within 100 presses of
+// each button and the rest are drawn until they can't
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut mechines: Vec<String> = Vec::new();
+
+    while mechines.len() < size {
+        let mut button = || (rng.range(10..=99) as u64, rng.range(10..=99) as u64);
+        let (button_a, button_b) = (button(), button());
+        let solvable = rng.chance(66);
+
+        let prize = if solvable {
+            let (a, b) = (rng.range(1..=100) as u64, rng.range(1..=100) as u64);
+            (a * button_a.0 + b * button_b.0, a * button_a.1 + b * button_b.1)
+        } else {
+            (rng.range(1000..=20000) as u64, rng.range(1000..=20000) as u64)
+        };
+
+        let mechine = Mechine {
+            button_a,
+            button_b,
+            prize,
+        };
+        if solvable != (lowest_cost_for_mechine(&mechine) > 0) {
+            continue;
+        }
+
+        mechines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            button_a.0, button_a.1, button_b.0, button_b.1, prize.0, prize.1
+        ));
+    }
+
+    return mechines.join("\n");
+}
+
+// get_mechines reads three lines at a time, so a missing line shifts every machine after it
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    return Ok(Input::read(path)?.blocks(3, |block| scan!(block, MECHINE_TEMPLATE, u64, u64, u64, u64, u64, u64)));
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(320)))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let mechines: Vec<Mechine> = match phase!("parse", get_mechines("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve mechines. {}", e);
+            return;
+        }
+    };
+
+    if let Some(selected) = utils::variant::requested() {
+        if let Err(e) = utils::variant::compare(&get_implementations(&mechines), &selected) {
+            println!("Error: Failed to compare implementations. {}", e);
+        }
+        return;
+    }
+
+    let cost = phase!("part one", get_cost_for_all_prizes(&mechines));
+    println!("cost for all prizes: {}", cost);
+    /*
+        let cost_with_precision_fix = get_cost_for_all_prizes(&fix_precision_errors(&mechines));
+        println!("cost for all prizes (with precision fix): {}", cost_with_precision_fix);
+    */
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::check::agree;
+    use utils::check::proptest::prelude::*;
+    use utils::generate::{read_back, Rng};
+    use utils::validate::problems_in;
+    use utils::variant::agreed;
+
+    #[test]
+    fn test_get_cost_for_all_prizes() {
+        let mechines: Vec<Mechine> = match get_mechines("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve mechines. {}", e);
+                return;
+            }
+        };
+
+        let result = get_cost_for_all_prizes(&mechines);
+        assert_eq!(result, 480);
+    }
+
+    #[test]
+    fn test_solving_matches_search() {
+        let button = || (0u64..=12, 0u64..=12);
+        let mechine = (button(), button(), (0u64..=400, 0u64..=400)).prop_map(|(button_a, button_b, prize)| Mechine {
+            button_a,
+            button_b,
+            prize,
+        });
+
+        if let Err(e) = agree(mechine, lowest_cost_by_search, |mechine| {
+            lowest_cost_by_solving(mechine, 100)
+        }) {
+            panic!(
+                "Error: Failed to match the search in test_solving_matches_search. {}",
+                e
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let mechines = match read_back(
+            "y2024-day-13",
+            &[("input.csv", generate(&mut Rng::new(1), 60))],
+            get_mechines,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve mechines in test_generate. {}", e);
+            }
+        };
+
+        let solvable = mechines
+            .iter()
+            .filter(|mechine| lowest_cost_by_search(mechine) > 0)
+            .count();
+        assert_eq!(mechines.len(), 60);
+        assert!(20 < solvable && solvable < 60);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        // the second machine lost its button b line
+        let broken = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                      Button A: X+26, Y+66\nPrize: X=12748, Y=12176\n\n";
+        let found = match problems_in("y2024-day-13-broken", &[("input.csv", broken.to_string())], validate) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![(Some(5), "5 lines is not a whole number of 3 line blocks".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        let mechines: Vec<Mechine> = match get_mechines("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!(
+                    "Error: Failed to retrieve mechines in test_implementations_agree. {}",
+                    e
+                );
+            }
+        };
+
+        let outcomes = match get_implementations(&mechines).run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run the implementations. {}", e);
+            }
+        };
+        assert_eq!(agreed(&outcomes), Ok(vec![(1, "480".to_string())]));
+        assert_eq!(
+            outcomes
+                .iter()
+                .filter(|outcome| outcome.experimental)
+                .map(|outcome| outcome.name)
+                .collect::<Vec<&str>>(),
+            vec!["genetic"]
+        );
+    }
+}
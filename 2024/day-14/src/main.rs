@@ -0,0 +1,284 @@
+// https://adventofcode.com/2024/day/14
+
+use std::error::Error;
+
+use utils::generate::Rng;
+use utils::geom::{Point, Vector};
+use utils::image::Renderer;
+use utils::sim::{Cell, CellKind, Frame, Simulation};
+use utils::validate::{Input, Problem};
+use utils::{get_lines, phase, scan};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Point,
+    velocity: Vector,
+}
+
+pub fn get_mechines(path: &str) -> Result<Vec<Robot>, Box<dyn Error>> {
+    return Ok(get_lines(path)?
+        .iter()
+        .map(|line| scan!(line, "p={},{} v={},{}", i64, i64, i64, i64))
+        .map(|robot| {
+            robot.map(|(pos_x, pos_y, delta_x, delta_y)| Robot {
+                position: Point::new(pos_x, pos_y),
+                velocity: Vector::new(delta_x, delta_y),
+            })
+        })
+        .collect::<Result<Vec<Robot>, _>>()?);
+}
+
+pub fn simulate_robots(robots: &[Robot], time: u32, map_x: i64, map_y: i64) -> u32 {
+    let positions: Vec<Point> = robots
+        .iter()
+        .map(|robot| (robot.position + robot.velocity * time as i64).rem_euclid(map_x, map_y))
+        .collect();
+
+    let mid_x = map_x / 2;
+    let mid_y = map_y / 2;
+
+    let mut quadrent_1: u32 = 0;
+    let mut quadrent_2: u32 = 0;
+    let mut quadrent_3: u32 = 0;
+    let mut quadrent_4: u32 = 0;
+
+    for position in positions {
+        if position.x < mid_x && position.y < mid_y {
+            quadrent_1 += 1;
+        }
+        if position.x > mid_x && position.y < mid_y {
+            quadrent_2 += 1;
+        }
+        if position.x < mid_x && position.y > mid_y {
+            quadrent_3 += 1;
+        }
+        if position.x > mid_x && position.y > mid_y {
+            quadrent_4 += 1;
+        }
+    }
+
+    return quadrent_1.max(1) * quadrent_2.max(1) * quadrent_3.max(1) * quadrent_4.max(1);
+}
+
+pub struct Restroom {
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
+    elapsed: u32,
+    time: u32,
+}
+
+impl Restroom {
+    pub fn new(robots: &[Robot], time: u32, width: i64, height: i64) -> Restroom {
+        return Restroom {
+            robots: robots.to_owned(),
+            width,
+            height,
+            elapsed: 0,
+            time,
+        };
+    }
+}
+
+impl Simulation for Restroom {
+    fn step(&mut self) {
+        for robot in &mut self.robots {
+            robot.position = (robot.position + robot.velocity).rem_euclid(self.width, self.height);
+        }
+        self.elapsed += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        return self.elapsed >= self.time;
+    }
+
+    // the puzzle shows how many robots share a tile
+    fn render(&self) -> Frame {
+        let mut counts: Vec<Vec<u32>> = vec![vec![0; self.width as usize]; self.height as usize];
+        for robot in &self.robots {
+            if let Some(count) = robot.position.get_mut(&mut counts) {
+                *count += 1;
+            }
+        }
+
+        return counts
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|count| match (*count, char::from_digit(*count, 10)) {
+                        (0, _) => Cell::new('.', CellKind::Empty),
+                        (_, Some(digit)) => Cell::new(digit, CellKind::Actor),
+                        (_, None) => Cell::new('+', CellKind::Highlight),
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    fn caption(&self) -> String {
+        return format!("second {}", self.elapsed);
+    }
+}
+
+// size robots somewhere in a width x height restroom, each moving less than a restroom's width or
+// height a second in either direction
+pub fn generate(rng: &mut Rng, size: usize, width: i64, height: i64) -> String {
+    let mut robots: Vec<String> = Vec::new();
+
+    for _ in 0..size {
+        let (x, y) = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        let (delta_x, delta_y) = (rng.range(1 - width..=width - 1), rng.range(1 - height..=height - 1));
+        robots.push(format!("p={},{} v={},{}\n", x, y, delta_x, delta_y));
+    }
+
+    return robots.concat();
+}
+
+// a position and a velocity a line, positions can not be negative
+pub fn validate(path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    return Ok(
+        Input::read(path)?.each(|line| match scan!(line, "p={},{} v={},{}", u64, u64, i64, i64) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }),
+    );
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let mut rng = request.rng();
+        let (width, height) = (request.width_or(101) as i64, request.height_or(103) as i64);
+        if let Err(e) = request.save(&[("input.csv", generate(&mut rng, request.size_or(500), width, height))]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input.csv")) {
+        return;
+    }
+
+    let robots: Vec<Robot> = match phase!("parse", get_mechines("data/input.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve robots. {}", e);
+            return;
+        }
+    };
+
+    if utils::sim::requested() {
+        if let Err(e) = utils::sim::play(&mut Restroom::new(&robots, 100, 101, 103)) {
+            println!("Error: Failed to play the simulation. {}", e);
+        }
+        return;
+    }
+
+    let renderer = Renderer {
+        cell_size: 3,
+        delay: 10,
+        ..Renderer::default()
+    };
+    if !utils::image::render_requested(|| Restroom::new(&robots, 100, 101, 103), &renderer) {
+        return;
+    }
+
+    let result = phase!("part one", simulate_robots(&robots, 100, 101, 103));
+    println!("{:#?}", result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_simulate_robots() {
+        let robots: Vec<Robot> = match get_mechines("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve robots. {}", e);
+                return;
+            }
+        };
+
+        let result = simulate_robots(&robots, 100, 11, 7);
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn test_restroom_frames() {
+        let robots: Vec<Robot> = match get_mechines("data/test.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve robots in test_restroom_frames. {}", e);
+            }
+        };
+
+        let frames = utils::sim::headless(&mut Restroom::new(&robots, 100, 11, 7), 100);
+        assert_eq!(frames.len(), 101);
+        assert_eq!(
+            frames[100],
+            [
+                "......2..1.",
+                "...........",
+                "1..........",
+                ".11........",
+                ".....1.....",
+                "...12......",
+                ".1....1....",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let robots = match read_back(
+            "y2024-day-14",
+            &[("input.csv", generate(&mut Rng::new(1), 50, 11, 7))],
+            get_mechines,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve robots in test_generate. {}", e);
+            }
+        };
+
+        assert_eq!(robots.len(), 50);
+        assert!(robots.iter().all(|robot| robot.position.index(7, 11).is_some()));
+        assert!(robots
+            .iter()
+            .all(|robot| robot.velocity.x.abs() < 11 && robot.velocity.y.abs() < 7));
+        assert!(simulate_robots(&robots, 100, 11, 7) > 0);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test.csv").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-14-broken",
+            &[("input.csv", "p=0,4 v=3,-3\np=-6,3 v=-1,-3\np=1,2\n".to_string())],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (
+                    Some(2),
+                    "field 1 (\"-6\") of \"p=-6,3 v=-1,-3\" does not parse".to_string()
+                ),
+                (Some(3), "\"p=1,2\" does not match \"p={},{} v={},{}\"".to_string())
+            ]
+        );
+    }
+}
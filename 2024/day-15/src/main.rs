@@ -0,0 +1,477 @@
+// https://adventofcode.com/2024/day/15
+
+use std::error::Error;
+
+use utils::generate::Rng;
+use utils::geom::{Point, Vector};
+use utils::get_csv_data;
+use utils::image::Renderer;
+use utils::phase;
+use utils::sim::{Cell, CellKind, Frame, Simulation};
+use utils::validate::{Input, Problem};
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum MapState {
+    Wall,
+    Box,
+    Floor,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Moves {
+    Left,
+    Up,
+    Down,
+    Right,
+}
+
+impl Moves {
+    fn direction(&self) -> Vector {
+        return match self {
+            Moves::Left => Vector::LEFT,
+            Moves::Up => Vector::UP,
+            Moves::Down => Vector::DOWN,
+            Moves::Right => Vector::RIGHT,
+        };
+    }
+}
+
+type MapResult = Result<(Vec<Vec<MapState>>, Point), Box<dyn Error>>;
+
+pub fn get_map(path: &str) -> MapResult {
+    let mut map: Vec<Vec<MapState>> = Vec::new();
+    let mut bot = Point::default();
+
+    let mut input_map: Vec<Vec<String>> = get_csv_data(path, false)?;
+
+    for i in 0..input_map.len() {
+        let mut row: Vec<String> = input_map[i][0].split("").map(String::from).collect();
+
+        if row.first() == Some(&String::from("")) {
+            row.remove(0);
+        }
+
+        if row.last() == Some(&String::from("")) {
+            row.pop();
+        }
+        input_map[i] = row;
+    }
+
+    for i in 0..input_map.len() {
+        let mut row: Vec<MapState> = Vec::new();
+        for j in 0..input_map[i].len() {
+            if input_map[i][j] == *"@" {
+                bot = Point::from_index((i, j));
+                row.push(MapState::Floor);
+            } else if input_map[i][j] == *"#" {
+                row.push(MapState::Wall);
+            } else if input_map[i][j] == *"O" {
+                row.push(MapState::Box);
+            } else {
+                row.push(MapState::Floor);
+            }
+        }
+        map.push(row);
+    }
+
+    Ok((map, bot))
+}
+
+pub fn get_moves(path: &str) -> Result<Vec<Moves>, Box<dyn Error>> {
+    let mut moves: Vec<Moves> = Vec::new();
+    let input: Vec<Vec<String>> = get_csv_data(path, false)?;
+    let moves_string: Vec<String> = input[0][0].split("").map(String::from).collect::<Vec<String>>();
+
+    for bot_move in moves_string {
+        if bot_move == *"<" {
+            moves.push(Moves::Left);
+        } else if bot_move == *"^" {
+            moves.push(Moves::Up);
+        } else if bot_move == *"v" {
+            moves.push(Moves::Down);
+        } else if bot_move == *">" {
+            moves.push(Moves::Right);
+        }
+    }
+
+    return Ok(moves);
+}
+
+// anything off the map might as well be a wall
+fn get_state(map: &[Vec<MapState>], spot: Point) -> MapState {
+    return spot.get(map).cloned().unwrap_or(MapState::Wall);
+}
+
+fn push_boxes(map: &[Vec<MapState>], direction: Vector, bot: Point) -> (bool, Vec<MapState>) {
+    let mut go_to = bot;
+    let mut infront_of_bot: Vec<MapState> = Vec::new();
+    let mut available_spot = false;
+
+    while get_state(map, go_to) != MapState::Wall {
+        go_to += direction;
+
+        let state = get_state(map, go_to);
+        if state == MapState::Floor {
+            available_spot = true;
+        }
+        if state == MapState::Wall {
+            break;
+        }
+
+        infront_of_bot.push(state);
+    }
+
+    if infront_of_bot.is_empty() || !available_spot {
+        return (false, infront_of_bot);
+    }
+
+    let mut index: usize = 0;
+    while infront_of_bot[index] != MapState::Floor {
+        index += 1;
+    }
+
+    infront_of_bot[index] = MapState::Box;
+    infront_of_bot[0] = MapState::Floor;
+
+    return (true, infront_of_bot);
+}
+
+pub struct Warehouse {
+    map: Vec<Vec<MapState>>,
+    bot: Point,
+    moves: Vec<Moves>,
+    next: usize,
+}
+
+impl Warehouse {
+    pub fn new(map: &[Vec<MapState>], moves: &[Moves], bot: Point) -> Warehouse {
+        return Warehouse {
+            map: map.to_owned(),
+            bot,
+            moves: moves.to_vec(),
+            next: 0,
+        };
+    }
+
+    fn get_sum_of_gps_chords(&self) -> u32 {
+        let mut sum_of_gps_chords: u32 = 0;
+
+        for i in 0..self.map.len() {
+            for j in 0..self.map[0].len() {
+                if self.map[i][j] == MapState::Box {
+                    sum_of_gps_chords += (100 * i as u32) + j as u32;
+                }
+            }
+        }
+
+        return sum_of_gps_chords;
+    }
+}
+
+impl Simulation for Warehouse {
+    fn step(&mut self) {
+        let direction = self.moves[self.next].direction();
+        let go_to = self.bot + direction;
+        self.next += 1;
+
+        match get_state(&self.map, go_to) {
+            MapState::Wall => {}
+            MapState::Floor => self.bot = go_to,
+            MapState::Box => {
+                let (can_push, new_values) = push_boxes(&self.map, direction, self.bot);
+                let mut update_spot = self.bot;
+
+                if can_push {
+                    for update in new_values {
+                        update_spot += direction;
+
+                        if let Some(cell) = update_spot.get_mut(&mut self.map) {
+                            *cell = update;
+                        }
+                    }
+
+                    self.bot = go_to;
+                }
+            }
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        return self.next >= self.moves.len();
+    }
+
+    fn render(&self) -> Frame {
+        let mut frame: Frame = self
+            .map
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|state| match state {
+                        MapState::Wall => Cell::new('#', CellKind::Wall),
+                        MapState::Box => Cell::new('O', CellKind::Item),
+                        MapState::Floor => Cell::new('.', CellKind::Empty),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if let Some(cell) = self.bot.get_mut(&mut frame) {
+            *cell = Cell::new('@', CellKind::Actor);
+        }
+
+        return frame;
+    }
+
+    fn caption(&self) -> String {
+        return format!("move {} of {}", self.next, self.moves.len());
+    }
+}
+
+pub fn simulate_robot(map: &[Vec<MapState>], moves: &[Moves], initial: Point) -> u32 {
+    let mut warehouse = Warehouse::new(map, moves, initial);
+
+    while !warehouse.is_done() {
+        warehouse.step();
+    }
+
+    return warehouse.get_sum_of_gps_chords();
+}
+
+// a size x size warehouse walled in all round with about a quarter of the floor covered in boxes
+// and a tenth in more walls, plus 400 moves for every row on one line
+pub fn generate(rng: &mut Rng, size: usize) -> (String, String) {
+    let mut warehouse: Vec<Vec<char>> = (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| match rng.below(100) {
+                    _ if i == 0 || j == 0 || i + 1 == size || j + 1 == size => '#',
+                    0..=8 => '#',
+                    9..=33 => 'O',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+
+    let (row, col) = (size / 2, size / 2);
+    if let Some(spot) = warehouse.get_mut(row).and_then(|line| line.get_mut(col)) {
+        *spot = '@';
+    }
+
+    let moves: String = (0..size * 400)
+        .map(|_| *rng.pick(&['<', '^', 'v', '>']).unwrap_or(&'<'))
+        .collect();
+    let map: String = warehouse
+        .iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect();
+
+    return (map, format!("{}\n", moves));
+}
+
+// the warehouse has to be rectangular with exactly one robot, and get_moves only reads the first
+// line of moves
+pub fn validate(directory: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
+    let map = Input::read(&format!("{}/map.csv", directory))?;
+    let moves = Input::read(&format!("{}/moves.csv", directory))?;
+
+    return Ok([
+        map.rectangular(),
+        map.only(|spot| "#.O@".contains(spot)),
+        map.exactly('@', 1),
+        moves.single_line(),
+        moves.only(|step| "<^v>".contains(step)),
+    ]
+    .concat());
+}
+
+fn main() {
+    utils::trace::init();
+
+    if let Some(request) = utils::generate::requested() {
+        let (map, moves) = generate(&mut request.rng(), request.size_or(50));
+        if let Err(e) = request.save(&[("input/map.csv", map), ("input/moves.csv", moves)]) {
+            println!("Error: Failed to write the generated input. {}", e);
+        }
+        return;
+    }
+
+    if !utils::validate::check(validate("data/input")) {
+        return;
+    }
+
+    let (map, initial) = match phase!("parse", get_map("data/input/map.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve map. {}", e);
+            return;
+        }
+    };
+
+    let moves = match phase!("parse", get_moves("data/input/moves.csv")) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("Error: Failed to retrieve moves. {}", e);
+            return;
+        }
+    };
+
+    if utils::sim::requested() {
+        if let Err(e) = utils::sim::play(&mut Warehouse::new(&map, &moves, initial)) {
+            println!("Error: Failed to play the simulation. {}", e);
+        }
+        return;
+    }
+
+    let renderer = Renderer {
+        cell_size: 6,
+        every: 50,
+        ..Renderer::default()
+    };
+    if !utils::image::render_requested(|| Warehouse::new(&map, &moves, initial), &renderer) {
+        return;
+    }
+
+    let result = phase!("part one", simulate_robot(&map, &moves, initial));
+    println!("{}", result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::generate::read_back;
+    use utils::validate::problems_in;
+
+    #[test]
+    fn test_simulate_robot() {
+        let (map, initial) = match get_map("data/test/map.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve map. {}", e);
+                return;
+            }
+        };
+
+        let moves = match get_moves("data/test/moves.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error: Failed to retrieve moves. {}", e);
+                return;
+            }
+        };
+
+        let result = simulate_robot(&map, &moves, initial);
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_warehouse_frames() {
+        let (map, initial) = match get_map("data/test/map.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve map in test_warehouse_frames. {}", e);
+            }
+        };
+
+        let moves = match get_moves("data/test/moves.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve moves in test_warehouse_frames. {}", e);
+            }
+        };
+
+        let frames = utils::sim::headless(&mut Warehouse::new(&map, &moves, initial), moves.len());
+        assert_eq!(frames.len(), moves.len() + 1);
+        assert_eq!(
+            frames[frames.len() - 1],
+            [
+                "##########",
+                "#.O.O.OOO#",
+                "#........#",
+                "#OO......#",
+                "#OO@.....#",
+                "#O#.....O#",
+                "#O.....OO#",
+                "#O.....OO#",
+                "#OO....OO#",
+                "##########",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let (map, moves) = generate(&mut Rng::new(1), 10);
+        let files = [("input/map.csv", map), ("input/moves.csv", moves)];
+
+        let ((map, initial), moves) = match read_back("y2024-day-15", &files, |input| {
+            Ok((
+                get_map(&format!("{}/map.csv", input))?,
+                get_moves(&format!("{}/moves.csv", input))?,
+            ))
+        }) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!(
+                    "Error: Failed to retrieve the generated map and moves in test_generate. {}",
+                    e
+                );
+            }
+        };
+
+        assert_eq!((initial, moves.len()), (Point::new(5, 5), 4000));
+        assert!(map[0].iter().all(|spot| *spot == MapState::Wall));
+
+        let boxes = map.iter().flatten().filter(|spot| **spot == MapState::Box).count();
+        let mut warehouse = Warehouse::new(&map, &moves, initial);
+        while !warehouse.is_done() {
+            warehouse.step();
+        }
+        assert_eq!(
+            warehouse
+                .map
+                .iter()
+                .flatten()
+                .filter(|spot| **spot == MapState::Box)
+                .count(),
+            boxes
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("data/test").ok(), Some(Vec::new()));
+
+        let found = match problems_in(
+            "y2024-day-15-broken",
+            &[
+                ("input/map.csv", "####\n#@.#\n#O@#\n####\n".to_string()),
+                ("input/moves.csv", "<^^>\nvv<x\n".to_string()),
+            ],
+            validate,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to validate the broken input in test_validate. {}", e);
+            }
+        };
+
+        assert_eq!(
+            found,
+            vec![
+                (Some(3), "expected 1 '@' but found 2".to_string()),
+                (Some(2), "expected one line but found 2".to_string()),
+                (Some(2), "unexpected 'x' in column 4".to_string())
+            ]
+        );
+
+        // get_map reads each row's own width so the warehouse does not have to be square
+        let wide = read_back(
+            "y2024-day-15-wide",
+            &[("map.csv", "######\n#@.O.#\n######\n".to_string())],
+            get_map,
+        );
+        assert_eq!(wide.ok().map(|(map, _)| map[1].len()), Some(6));
+    }
+}
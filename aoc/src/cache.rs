@@ -0,0 +1,248 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use utils::get_csv_data;
+use utils::puzzle::Puzzle;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedAnswer {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub source: String,
+    pub answer: String,
+}
+
+// fnv-1a, stable across builds unlike the std hasher
+fn hash_bytes(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    return hash;
+}
+
+// file names are hashed along with the contents so renames and moves count as changes,
+// anything missing is skipped
+fn hash_path(hash: u64, path: &Path) -> Result<u64, Box<dyn Error>> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut hash = hash_bytes(hash, name.as_bytes());
+
+    if path.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(path)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect();
+        entries.sort();
+
+        for entry in entries {
+            hash = hash_path(hash, &entry)?;
+        }
+    } else if path.is_file() {
+        hash = hash_bytes(hash, &fs::read(path)?);
+    }
+
+    return Ok(hash);
+}
+
+pub fn hash_paths(paths: &[String]) -> Result<String, Box<dyn Error>> {
+    let mut hash = FNV_OFFSET;
+
+    for path in paths {
+        hash = hash_path(hash, Path::new(path))?;
+    }
+
+    return Ok(format!("{:016x}", hash));
+}
+
+// the day reads data/input.csv or everything under data/input/
+pub fn input_hash(workspace: &str, puzzle: Puzzle) -> Result<String, Box<dyn Error>> {
+    let directory = format!("{}/{}/data", workspace, puzzle.directory());
+    return hash_paths(&[format!("{}/input.csv", directory), format!("{}/input", directory)]);
+}
+
+// the binary is built from the day's crate, utils and the dependency versions in Cargo.lock, so
+// hashing those stands in for hashing the binary without having to build it first
+pub fn source_hash(workspace: &str, puzzle: Puzzle) -> Result<String, Box<dyn Error>> {
+    let directory = format!("{}/{}", workspace, puzzle.directory());
+
+    return hash_paths(&[
+        format!("{}/src", directory),
+        format!("{}/Cargo.toml", directory),
+        format!("{}/utils/src", workspace),
+        format!("{}/utils/Cargo.toml", workspace),
+        format!("{}/Cargo.lock", workspace),
+    ]);
+}
+
+pub fn get_cached(path: &str) -> Result<Vec<CachedAnswer>, Box<dyn Error>> {
+    let mut cached: Vec<CachedAnswer> = Vec::new();
+
+    if !Path::new(path).exists() {
+        return Ok(cached);
+    }
+
+    let rows: Vec<Vec<String>> = get_csv_data(path, true)?;
+
+    for row in rows {
+        if row.len() < 5 {
+            return Err("cache row has fewer than 5 columns".into());
+        }
+
+        cached.push(CachedAnswer {
+            day: row[0].parse::<u32>()?,
+            part: row[1].parse::<u32>()?,
+            input: row[2].clone(),
+            source: row[3].clone(),
+            answer: row[4].clone(),
+        });
+    }
+
+    return Ok(cached);
+}
+
+pub fn save_cached(path: &str, cached: &[CachedAnswer]) -> Result<(), Box<dyn Error>> {
+    if let Some(directory) = Path::new(path).parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(["day", "part", "input", "source", "answer"])?;
+
+    for answer in cached {
+        writer.write_record([
+            answer.day.to_string(),
+            answer.part.to_string(),
+            answer.input.clone(),
+            answer.source.clone(),
+            answer.answer.clone(),
+        ])?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+// every part of the day has to be cached under the same hashes for it to count as a hit
+pub fn lookup(cached: &[CachedAnswer], day: u32, input: &str, source: &str) -> Option<Vec<String>> {
+    let mut answers: Vec<&CachedAnswer> = cached
+        .iter()
+        .filter(|answer| answer.day == day && answer.input == input && answer.source == source)
+        .collect();
+
+    if answers.is_empty() {
+        return None;
+    }
+
+    answers.sort_by_key(|answer| answer.part);
+    return Some(answers.iter().map(|answer| answer.answer.clone()).collect());
+}
+
+// a new run replaces everything cached for the day, stale hashes included
+pub fn store(cached: &mut Vec<CachedAnswer>, day: u32, input: &str, source: &str, answers: &[String]) {
+    cached.retain(|answer| answer.day != day);
+
+    for (i, answer) in answers.iter().enumerate() {
+        cached.push(CachedAnswer {
+            day,
+            part: i as u32 + 1,
+            input: input.to_string(),
+            source: source.to_string(),
+            answer: answer.clone(),
+        });
+    }
+
+    cached.sort_by_key(|answer| (answer.day, answer.part));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let mut cached = match get_cached("data/test/cache.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve cache. {}", e);
+            }
+        };
+
+        assert_eq!(
+            lookup(&cached, 6, "00000000000000aa", "00000000000000bb"),
+            Some(vec!["4819".to_string(), "1796".to_string()])
+        );
+        assert_eq!(lookup(&cached, 6, "00000000000000aa", "00000000000000cc"), None);
+
+        store(
+            &mut cached,
+            6,
+            "00000000000000aa",
+            "00000000000000cc",
+            &["4819".to_string()],
+        );
+
+        assert_eq!(lookup(&cached, 6, "00000000000000aa", "00000000000000bb"), None);
+        assert_eq!(
+            lookup(&cached, 6, "00000000000000aa", "00000000000000cc"),
+            Some(vec!["4819".to_string()])
+        );
+        assert_eq!(cached.len(), 2);
+    }
+
+    #[test]
+    fn test_hashes() {
+        let workspace = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let puzzle = Puzzle::new(2024, 1);
+        let data = workspace.join(puzzle.directory()).join("data");
+        let workspace = workspace.to_string_lossy().to_string();
+
+        if let Err(e) = fs::create_dir_all(&data) {
+            panic!("Error: Failed to create test directory. {}", e);
+        }
+
+        let mut hashes: Vec<String> = Vec::new();
+        for input in ["3   4\n", "3   4\n", "3   5\n"] {
+            if let Err(e) = fs::write(data.join("input.csv"), input) {
+                panic!("Error: Failed to write test input. {}", e);
+            }
+
+            match input_hash(&workspace, puzzle) {
+                Ok(result) => hashes.push(result),
+                Err(e) => {
+                    panic!("Error: Failed to hash input. {}", e);
+                }
+            }
+        }
+
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(hashes[0].len(), 16);
+
+        // a dependency bump only shows up in Cargo.lock
+        let mut sources: Vec<String> = Vec::new();
+        for lock in ["version = \"1.7.0\"\n", "version = \"1.8.0\"\n"] {
+            if let Err(e) = fs::write(format!("{}/Cargo.lock", workspace), lock) {
+                panic!("Error: Failed to write test lock file. {}", e);
+            }
+
+            match source_hash(&workspace, puzzle) {
+                Ok(result) => sources.push(result),
+                Err(e) => {
+                    panic!("Error: Failed to hash sources. {}", e);
+                }
+            }
+        }
+        assert_ne!(sources[0], sources[1]);
+
+        let _ = fs::remove_dir_all(&workspace);
+    }
+}
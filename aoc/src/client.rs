@@ -0,0 +1,25 @@
+use std::env;
+use std::error::Error;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/Ukcoop/advent-of-code-2024-rs";
+
+// AOC_BASE_URL lets tests and mirrors stand in for the real site
+pub fn base_url() -> String {
+    return env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+}
+
+pub fn session() -> Option<String> {
+    return env::var("AOC_SESSION").ok().filter(|session| !session.is_empty());
+}
+
+pub fn get(url: &str, session: Option<&str>) -> Result<String, Box<dyn Error>> {
+    let mut request = ureq::get(url).header("User-Agent", USER_AGENT);
+
+    if let Some(session) = session {
+        request = request.header("Cookie", &format!("session={}", session));
+    }
+
+    let body = request.call()?.body_mut().read_to_string()?;
+    return Ok(body);
+}
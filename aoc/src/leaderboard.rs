@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::client;
+use utils::puzzle::Puzzle;
+
+use crate::times::format_duration;
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+pub struct Ranking {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u32,
+    pub site_score: u32,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        return match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        };
+    }
+
+    pub fn star_time(&self, day: u32, part: u32) -> Option<u64> {
+        return self
+            .completion_day_level
+            .get(&day.to_string())
+            .and_then(|parts| parts.get(&part.to_string()))
+            .map(|star| star.get_star_ts);
+    }
+}
+
+pub fn parse_leaderboard(json: &str) -> Result<Leaderboard, Box<dyn Error>> {
+    return Ok(serde_json::from_str(json)?);
+}
+
+// a saved JSON file is used as is, anything else is treated as a leaderboard id on the site
+pub fn get_leaderboard(source: &str, year: u32) -> Result<Leaderboard, Box<dyn Error>> {
+    if Path::new(source).exists() {
+        return parse_leaderboard(&fs::read_to_string(source)?);
+    }
+
+    let session = match client::session() {
+        Some(session) => session,
+        None => return Err("AOC_SESSION is required to download a private leaderboard".into()),
+    };
+
+    let url = format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        client::base_url(),
+        year,
+        source
+    );
+    return parse_leaderboard(&client::get(&url, Some(&session))?);
+}
+
+// every star is worth one point per member who got it later (or not at all)
+pub fn local_scores(leaderboard: &Leaderboard) -> HashMap<u64, u32> {
+    let member_count = leaderboard.members.len() as u32;
+    let mut scores: HashMap<u64, u32> = leaderboard.members.values().map(|member| (member.id, 0)).collect();
+
+    for day in 1..=25 {
+        for part in 1..=2 {
+            let mut finishers: Vec<(u64, u64)> = leaderboard
+                .members
+                .values()
+                .filter_map(|member| member.star_time(day, part).map(|time| (time, member.id)))
+                .collect();
+            finishers.sort();
+
+            for (rank, (_, id)) in finishers.iter().enumerate() {
+                *scores.entry(*id).or_insert(0) += member_count - rank as u32;
+            }
+        }
+    }
+
+    return scores;
+}
+
+pub fn get_rankings(leaderboard: &Leaderboard) -> Vec<Ranking> {
+    let scores = local_scores(leaderboard);
+
+    let mut rankings: Vec<Ranking> = leaderboard
+        .members
+        .values()
+        .map(|member| Ranking {
+            id: member.id,
+            name: member.display_name(),
+            stars: member.stars,
+            local_score: scores.get(&member.id).copied().unwrap_or(0),
+            site_score: member.local_score,
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| b.local_score.cmp(&a.local_score).then(a.id.cmp(&b.id)));
+    return rankings;
+}
+
+pub fn render_rankings(leaderboard: &Leaderboard) -> String {
+    let mut output = format!("leaderboard {}\n", leaderboard.event);
+
+    for (i, ranking) in get_rankings(leaderboard).iter().enumerate() {
+        output += &format!(
+            "{:>3}) {:>5} {:>3}* {}",
+            i + 1,
+            ranking.local_score,
+            ranking.stars,
+            ranking.name
+        );
+
+        // the site skips stars on days that were cancelled, so point out when the recount disagrees
+        if ranking.site_score != ranking.local_score {
+            output += &format!(" (site: {})", ranking.site_score);
+        }
+        output += "\n";
+    }
+
+    return output;
+}
+
+pub fn render_day(leaderboard: &Leaderboard, day: u32) -> Result<String, Box<dyn Error>> {
+    let unlock = Puzzle::new(leaderboard.event.parse::<u32>()?, day).unlock_time();
+    let mut output = format!("day {}\n{:>8} {:>10} {:>10} name\n", day, "part 1", "part 2", "delta");
+
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .values()
+        .filter(|member| member.star_time(day, 1).is_some())
+        .collect();
+    members.sort_by_key(|member| (member.star_time(day, 2).unwrap_or(u64::MAX), member.star_time(day, 1)));
+
+    for member in members {
+        let part_one = member.star_time(day, 1);
+        let part_two = member.star_time(day, 2);
+
+        let delta = match (part_one, part_two) {
+            (Some(one), Some(two)) => format_duration(two.saturating_sub(one)),
+            _ => "-".to_string(),
+        };
+
+        output += &format!(
+            "{:>8} {:>10} {:>10} {}\n",
+            part_one.map_or("-".to_string(), |time| format_duration(time.saturating_sub(unlock))),
+            part_two.map_or("-".to_string(), |time| format_duration(time.saturating_sub(unlock))),
+            delta,
+            member.display_name()
+        );
+    }
+
+    return Ok(output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_leaderboard() -> Leaderboard {
+        return match get_leaderboard("data/test/leaderboard.json", 2024) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve leaderboard. {}", e);
+            }
+        };
+    }
+
+    #[test]
+    fn test_get_rankings() {
+        let leaderboard = test_leaderboard();
+        let rankings = get_rankings(&leaderboard);
+
+        for ranking in &rankings {
+            assert_eq!(ranking.local_score, ranking.site_score);
+        }
+
+        assert_eq!(rankings[0].name, "alice");
+        assert_eq!(rankings[2].name, "(anonymous user #3)");
+    }
+
+    #[test]
+    fn test_render_day() {
+        let output = match render_day(&test_leaderboard(), 1) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to render day. {}", e);
+            }
+        };
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[2], "00:01:40   00:03:20   00:01:40 alice");
+        assert_eq!(lines[3], "00:00:50   00:06:40   00:05:50 bob");
+        assert_eq!(lines[4], "00:05:00          -          - (anonymous user #3)");
+    }
+}
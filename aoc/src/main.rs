@@ -0,0 +1,461 @@
+mod cache;
+mod client;
+mod leaderboard;
+mod progress;
+mod read;
+mod runner;
+mod stars;
+mod times;
+mod watch;
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use progress::{get_answers, get_stars, record_answer, save_answers, Answer};
+use runner::{animate_day, generate_day, get_days, get_years, run_day, validate_day};
+use stars::render_readme_section;
+use times::{get_times, now, record_fetched, record_solved, save_times};
+use utils::examples::{get_examples, write_examples};
+use utils::generate::Request;
+use utils::puzzle::Puzzle;
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+const FIRST_YEAR: u32 = 2015;
+
+fn workspace_path(path: &str) -> String {
+    return format!("{}/{}", WORKSPACE, path);
+}
+
+// answers, times and charts live next to that year's days
+fn year_path(year: u32, path: &str) -> String {
+    return format!("{}/{}/{}", WORKSPACE, year, path);
+}
+
+// AOC_YEAR picks the year when none is given, otherwise the newest year in the workspace
+fn default_year() -> Result<u32, Box<dyn Error>> {
+    if let Ok(year) = std::env::var("AOC_YEAR") {
+        return Ok(year.parse::<u32>()?);
+    }
+
+    return match get_years(WORKSPACE)?.last() {
+        Some(year) => Ok(*year),
+        None => Err("no years in the workspace".into()),
+    };
+}
+
+fn cache_path(year: u32) -> String {
+    return workspace_path(&format!(".cache/{}/results.csv", year));
+}
+
+// plain runs come from the answer cache while the input and the day's sources are unchanged,
+// --no-cache or any flag for the day binary runs it for real
+fn run(puzzle: Puzzle, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let no_cache = args.iter().any(|arg| arg == "--no-cache");
+    let args: Vec<String> = args.iter().filter(|arg| *arg != "--no-cache").cloned().collect();
+
+    let answers = get_answers(&year_path(puzzle.year, "answers.csv"))?;
+    let mut times = get_times(&year_path(puzzle.year, "times.csv"))?;
+    let mut timed = false;
+
+    let input = cache::input_hash(WORKSPACE, puzzle)?;
+    let source = cache::source_hash(WORKSPACE, puzzle)?;
+    let mut cached = cache::get_cached(&cache_path(puzzle.year))?;
+
+    let (results, from_cache) = match cache::lookup(&cached, puzzle.day, &input, &source) {
+        Some(results) if !no_cache && args.is_empty() => (results, true),
+        _ => {
+            let results = run_day(WORKSPACE, puzzle, &args)?;
+            // --impl can run only some of the parts, so what it found is not the day's answers
+            if !args.iter().any(|arg| arg == "--impl") {
+                cache::store(&mut cached, puzzle.day, &input, &source, &results);
+                cache::save_cached(&cache_path(puzzle.year), &cached)?;
+            }
+            (results, false)
+        }
+    };
+    let finished = now();
+
+    for (i, result) in results.iter().enumerate() {
+        let part = i as u32 + 1;
+        if result.is_empty() {
+            continue;
+        }
+
+        let expected = answers
+            .iter()
+            .find(|answer| answer.day == puzzle.day && answer.part == part);
+
+        let status = match expected {
+            Some(answer) if answer.answer == *result => {
+                // a cached answer was solved some other time, only a real run is timed
+                if !from_cache {
+                    timed |= record_solved(&mut times, puzzle.day, part, finished);
+                }
+                "correct"
+            }
+            Some(_) => "wrong",
+            None => "unknown",
+        };
+
+        println!(
+            "{} day {} part {}: {} ({}{})",
+            puzzle.year,
+            puzzle.day,
+            part,
+            result,
+            status,
+            if from_cache { ", cached" } else { "" }
+        );
+    }
+
+    if timed {
+        save_times(&year_path(puzzle.year, "times.csv"), &times)?;
+    }
+    return Ok(());
+}
+
+fn all(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    for day in get_days(WORKSPACE, year) {
+        run(Puzzle::new(year, day), args)?;
+    }
+
+    return Ok(());
+}
+
+fn stars(year: u32) -> Result<(), Box<dyn Error>> {
+    let answers = get_answers(&year_path(year, "answers.csv"))?;
+    let stars = get_stars(&answers);
+
+    fs::write(year_path(year, "stars-collected.svg"), stars::render_chart(&stars))?;
+
+    let readme = fs::read_to_string(workspace_path("README.md"))?;
+    let section = render_readme_section(year, &stars, &format!("{}/stars-collected.svg", year));
+    fs::write(
+        workspace_path("README.md"),
+        stars::update_readme(&readme, year, &section)?,
+    )?;
+
+    println!("stars collected in {}: {}", year, stars.values().sum::<u32>());
+    return Ok(());
+}
+
+fn times(year: u32) -> Result<(), Box<dyn Error>> {
+    let times = get_times(&year_path(year, "times.csv"))?;
+    fs::write(year_path(year, "times.svg"), times::render_chart(year, &times))?;
+
+    for time in &times {
+        if let Some(solved) = time.solved {
+            let elapsed = solved.saturating_sub(Puzzle::new(year, time.day).unlock_time());
+            println!(
+                "day {} part {}: {}",
+                time.day,
+                time.part,
+                times::format_duration(elapsed)
+            );
+        }
+    }
+
+    return Ok(());
+}
+
+fn leaderboard(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err("usage: aoc [year] leaderboard <id|file.json> [day]".into());
+    }
+
+    let leaderboard = leaderboard::get_leaderboard(&args[0], year)?;
+    print!("{}", leaderboard::render_rankings(&leaderboard));
+
+    let days: Vec<u32> = match args.get(1) {
+        Some(day) => vec![day.parse::<u32>()?],
+        None => (1..=25).collect(),
+    };
+
+    for day in days {
+        if leaderboard
+            .members
+            .values()
+            .any(|member| member.star_time(day, 1).is_some())
+        {
+            println!();
+            print!("{}", leaderboard::render_day(&leaderboard, day)?);
+        }
+    }
+
+    return Ok(());
+}
+
+fn answer(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 3 {
+        return Err("usage: aoc [year] answer <day> <part> <answer>".into());
+    }
+
+    let path = year_path(year, "answers.csv");
+    let mut answers = get_answers(&path)?;
+    let (day, part) = (args[0].parse::<u32>()?, args[1].parse::<u32>()?);
+
+    record_answer(
+        &mut answers,
+        Answer {
+            day,
+            part,
+            answer: args[2].clone(),
+        },
+    );
+    save_answers(&path, &answers)?;
+
+    // recording an answer means it was just submitted and accepted
+    let mut times = get_times(&year_path(year, "times.csv"))?;
+    if record_solved(&mut times, day, part, now()) {
+        save_times(&year_path(year, "times.csv"), &times)?;
+    }
+
+    // part two only shows up on the page once part one is accepted
+    let page = workspace_path(&Puzzle::new(year, day).cache_path("puzzle.html"));
+    if part == 1 && Path::new(&page).exists() {
+        fs::remove_file(page)?;
+    }
+
+    return Ok(());
+}
+
+// downloads a day's input as the site serves it into the puzzle cache and logs that as when it was
+// fetched, days that read their input in another shape are converted from there
+fn fetch(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 1 {
+        return Err("usage: aoc [year] fetch <day>".into());
+    }
+
+    let puzzle = Puzzle::new(year, args[0].parse::<u32>()?);
+    let path = workspace_path(&puzzle.cache_path("input.txt"));
+    if Path::new(&path).exists() {
+        println!("already fetched {}", puzzle.cache_path("input.txt"));
+        return Ok(());
+    }
+
+    let session = match client::session() {
+        Some(session) => session,
+        None => return Err("AOC_SESSION is needed to download an input".into()),
+    };
+    let input = client::get(
+        &format!("{}{}/input", client::base_url(), puzzle.url_path()),
+        Some(&session),
+    )?;
+
+    if let Some(directory) = Path::new(&path).parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(&path, input)?;
+
+    let fetched = now();
+    let mut times = get_times(&year_path(year, "times.csv"))?;
+    let first = record_fetched(&mut times, puzzle.day, 1, fetched);
+    if record_fetched(&mut times, puzzle.day, 2, fetched) || first {
+        save_times(&year_path(year, "times.csv"), &times)?;
+    }
+
+    println!("fetched {}", puzzle.cache_path("input.txt"));
+    return Ok(());
+}
+
+fn examples(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 1 {
+        return Err("usage: aoc [year] examples <day>".into());
+    }
+
+    let puzzle = Puzzle::new(year, args[0].parse::<u32>()?);
+    let page = read::get_page(
+        &client::base_url(),
+        client::session().as_deref(),
+        puzzle,
+        &workspace_path(&puzzle.cache_path("puzzle.html")),
+        false,
+    )?;
+    let found = get_examples(&page);
+
+    write_examples(
+        &found,
+        puzzle.day,
+        &workspace_path(&puzzle.directory()),
+        &year_path(year, "examples.csv"),
+    )?;
+
+    for example in &found {
+        println!(
+            "part {}: data/example-{}.csv should give {}",
+            example.part, example.block, example.answer
+        );
+    }
+
+    return Ok(());
+}
+
+// prints the puzzle as markdown, --save writes it to puzzle.md in the day's directory instead and
+// --refresh fetches the page again instead of reading the cached one
+fn read(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let flags = args.get(1..).unwrap_or_default();
+    let day = match args.first().map(|day| day.parse::<u32>()) {
+        Some(Ok(day)) if flags.iter().all(|flag| flag == "--save" || flag == "--refresh") => day,
+        _ => return Err("usage: aoc [year] read <day> [--save] [--refresh]".into()),
+    };
+
+    let puzzle = Puzzle::new(year, day);
+    let page = read::get_page(
+        &client::base_url(),
+        client::session().as_deref(),
+        puzzle,
+        &workspace_path(&puzzle.cache_path("puzzle.html")),
+        flags.iter().any(|flag| flag == "--refresh"),
+    )?;
+    let markdown = read::to_markdown(&page);
+
+    if flags.iter().any(|flag| flag == "--save") {
+        let path = format!("{}/puzzle.md", puzzle.directory());
+        fs::write(workspace_path(&path), markdown)?;
+        println!("saved {}", path);
+    } else {
+        print!("{}", markdown);
+    }
+
+    return Ok(());
+}
+
+fn clear_cache(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 1 || args[0] != "clear" {
+        return Err("usage: aoc [year] cache clear".into());
+    }
+
+    let cleared = cache::get_cached(&cache_path(year))?.len();
+    if cleared > 0 {
+        fs::remove_file(cache_path(year))?;
+    }
+
+    println!("cleared {} cached answers for {}", cleared, year);
+    return Ok(());
+}
+
+fn watch(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 1 {
+        return Err("usage: aoc [year] watch <day>".into());
+    }
+
+    return watch::watch(WORKSPACE, Puzzle::new(year, args[0].parse::<u32>()?));
+}
+
+fn animate(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.len() != 1 {
+        return Err("usage: aoc [year] animate <day>".into());
+    }
+
+    return animate_day(WORKSPACE, Puzzle::new(year, args[0].parse::<u32>()?));
+}
+
+// checks the shape of a day's input, or every day's when none is given, without solving anything
+fn validate(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let days = match args {
+        [] => get_days(WORKSPACE, year),
+        [day] => vec![day.parse::<u32>()?],
+        _ => return Err("usage: aoc [year] validate [day]".into()),
+    };
+
+    let mut found = 0;
+    for day in days {
+        let problems = validate_day(WORKSPACE, Puzzle::new(year, day))?;
+
+        if problems.is_empty() {
+            println!("{} day {}: no problems found", year, day);
+        }
+        for problem in &problems {
+            println!("{} day {}: {}", year, day, problem);
+        }
+        found += problems.len();
+    }
+
+    if found > 0 {
+        return Err(format!("found {} problems", found).into());
+    }
+
+    return Ok(());
+}
+
+// aoc [year] gen <day> [--seed N] [--size N] [--width N] [--height N] [--out <dir>], written to the
+// day's data/generated/seed-N unless --out says otherwise
+fn generate(year: u32, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let usage = "usage: aoc [year] gen <day> [--seed N] [--size N] [--width N] [--height N] [--out <dir>]";
+    let day = match args.first().map(|day| day.parse::<u32>()) {
+        Some(Ok(day)) if args.len() % 2 == 1 => day,
+        _ => return Err(usage.into()),
+    };
+
+    let (mut seed, mut size, mut width, mut height, mut out) = (0, None, None, None, None);
+    for pair in args[1..].chunks(2) {
+        match pair[0].as_str() {
+            "--seed" => seed = pair[1].parse::<u64>()?,
+            "--size" => size = Some(pair[1].parse::<usize>()?),
+            "--width" => width = Some(pair[1].parse::<usize>()?),
+            "--height" => height = Some(pair[1].parse::<usize>()?),
+            "--out" => out = Some(PathBuf::from(&pair[1])),
+            _ => return Err(usage.into()),
+        }
+    }
+
+    let puzzle = Puzzle::new(year, day);
+    let out = match out {
+        Some(out) => out,
+        None => PathBuf::from(workspace_path(&format!(
+            "{}/data/generated/seed-{}",
+            puzzle.directory(),
+            seed
+        ))),
+    };
+
+    let request = Request {
+        directory: out.to_string_lossy().to_string(),
+        seed,
+        size,
+        width,
+        height,
+    };
+
+    return generate_day(WORKSPACE, puzzle, &request);
+}
+
+fn command(args: &[String]) -> Result<(), Box<dyn Error>> {
+    // a leading year (aoc 2024 6) scopes the command, otherwise the default year is used
+    let (year, args) = match args.first().map(|arg| arg.parse::<u32>()) {
+        Some(Ok(year)) if year >= FIRST_YEAR => (year, &args[1..]),
+        _ => (default_year()?, args),
+    };
+
+    return match args.first().map(String::as_str) {
+        Some("all") => all(year, &args[1..]),
+        Some("stars") => stars(year),
+        Some("times") => times(year),
+        Some("leaderboard") => leaderboard(year, &args[1..]),
+        Some("answer") => answer(year, &args[1..]),
+        Some("fetch") => fetch(year, &args[1..]),
+        Some("examples") => examples(year, &args[1..]),
+        Some("read") => read(year, &args[1..]),
+        Some("watch") => watch(year, &args[1..]),
+        Some("animate") => animate(year, &args[1..]),
+        Some("validate") => validate(year, &args[1..]),
+        Some("gen") => generate(year, &args[1..]),
+        Some("cache") => clear_cache(year, &args[1..]),
+        Some(day) => match day.parse::<u32>() {
+            Ok(day) => run(Puzzle::new(year, day), &args[1..]),
+            Err(_) => Err(format!("unknown command {}", day).into()),
+        },
+        None => {
+            Err("usage: aoc [year] <day [-v|-vv] [--alloc] [--render <file>] [--svg <file>] [--impl <name|all>] [--no-cache]|all [--no-cache]|stars|times|leaderboard|answer|fetch|examples|read|watch|animate|validate|gen|cache clear>".into())
+        }
+    };
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(e) = command(&args) {
+        println!("Error: {}", e);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::path::Path;
+
+use utils::get_csv_data;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+}
+
+pub fn get_answers(path: &str) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let mut answers: Vec<Answer> = Vec::new();
+
+    if !Path::new(path).exists() {
+        return Ok(answers);
+    }
+
+    let rows: Vec<Vec<String>> = get_csv_data(path, true)?;
+
+    for row in rows {
+        if row.len() < 3 {
+            return Err("answers row has fewer than 3 columns".into());
+        }
+
+        answers.push(Answer {
+            day: row[0].parse::<u32>()?,
+            part: row[1].parse::<u32>()?,
+            answer: row[2].clone(),
+        });
+    }
+
+    return Ok(answers);
+}
+
+pub fn save_answers(path: &str, answers: &[Answer]) -> Result<(), Box<dyn Error>> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(["day", "part", "answer"])?;
+
+    for answer in answers {
+        writer.write_record([answer.day.to_string(), answer.part.to_string(), answer.answer.clone()])?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+// a correct answer replaces whatever was recorded for that part before
+pub fn record_answer(answers: &mut Vec<Answer>, new_answer: Answer) {
+    answers.retain(|answer| answer.day != new_answer.day || answer.part != new_answer.part);
+    answers.push(new_answer);
+    answers.sort_by_key(|answer| (answer.day, answer.part));
+}
+
+// a part answered on more than one row is still one star
+pub fn get_stars(answers: &[Answer]) -> BTreeMap<u32, u32> {
+    let mut stars: BTreeMap<u32, u32> = BTreeMap::new();
+    let solved: BTreeSet<(u32, u32)> = answers
+        .iter()
+        .filter(|answer| answer.part == 1 || answer.part == 2)
+        .map(|answer| (answer.day, answer.part))
+        .collect();
+
+    for (day, _) in solved {
+        *stars.entry(day).or_insert(0) += 1;
+    }
+
+    return stars;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_stars() {
+        let answers = match get_answers("data/test/answers.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve answers. {}", e);
+            }
+        };
+
+        let stars = get_stars(&answers);
+        assert_eq!(stars.get(&1), Some(&2));
+        assert_eq!(stars.get(&2), Some(&1));
+        assert_eq!(stars.get(&3), Some(&2));
+        assert_eq!(stars.values().sum::<u32>(), 5);
+
+        let answer = |day: u32, part: u32| Answer {
+            day,
+            part,
+            answer: "7".to_string(),
+        };
+        let stars = get_stars(&[answer(1, 1), answer(1, 1), answer(1, 2), answer(2, 3)]);
+        assert_eq!(stars.get(&1), Some(&2));
+        assert_eq!(stars.get(&2), None);
+    }
+
+    #[test]
+    fn test_record_answer() {
+        let mut answers = vec![Answer {
+            day: 2,
+            part: 1,
+            answer: "7".to_string(),
+        }];
+
+        record_answer(
+            &mut answers,
+            Answer {
+                day: 1,
+                part: 1,
+                answer: "11".to_string(),
+            },
+        );
+        record_answer(
+            &mut answers,
+            Answer {
+                day: 2,
+                part: 1,
+                answer: "2".to_string(),
+            },
+        );
+
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].day, 1);
+        assert_eq!(answers[1].answer, "2");
+    }
+}
@@ -0,0 +1,223 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use utils::examples::unescape_html;
+use utils::puzzle::Puzzle;
+
+use crate::client;
+
+// a cached page is used until refresh asks for it again, aoc answer drops it once part one is
+// accepted so the next read picks up part two
+pub fn get_page(
+    base_url: &str,
+    session: Option<&str>,
+    puzzle: Puzzle,
+    cache_path: &str,
+    refresh: bool,
+) -> Result<String, Box<dyn Error>> {
+    if let Ok(page) = fs::read_to_string(cache_path) {
+        if !refresh || session.is_none() {
+            return Ok(page);
+        }
+    }
+
+    let page = client::get(&format!("{}{}", base_url, puzzle.url_path()), session)?;
+
+    if let Some(directory) = Path::new(cache_path).parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(cache_path, &page)?;
+
+    return Ok(page);
+}
+
+fn get_attribute(tag: &str, name: &str) -> String {
+    let pattern = format!("{}=\"", name);
+
+    return match tag.find(&pattern) {
+        Some(start) => {
+            let value = &tag[start + pattern.len()..];
+            value[..value.find('"').unwrap_or(value.len())].to_string()
+        }
+        None => String::new(),
+    };
+}
+
+fn convert_article(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut link = String::new();
+
+    while let Some(start) = rest.find('<') {
+        let text = unescape_html(&rest[..start]);
+        if in_pre {
+            markdown += &text;
+        } else if !text.trim().is_empty() {
+            markdown += &text.replace('\n', " ");
+        }
+
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        let tag = &rest[start + 1..end];
+        let closing = tag.starts_with('/');
+        let name = tag.trim_start_matches('/').split_whitespace().next().unwrap_or("");
+
+        match (name, closing) {
+            ("h2", false) => markdown += "## ",
+            ("h2", true) | ("p", true) | ("ul", true) => markdown += "\n\n",
+            ("pre", false) => {
+                markdown += "```\n";
+                in_pre = true;
+            }
+            ("pre", true) => {
+                markdown += "```\n\n";
+                in_pre = false;
+            }
+            ("code", closing) if !in_pre => {
+                markdown += "`";
+                in_code = !closing;
+            }
+            ("em", _) if !in_pre && !in_code => markdown += "**",
+            ("li", false) => markdown += "- ",
+            ("li", true) => markdown += "\n",
+            ("a", false) => {
+                link = get_attribute(tag, "href");
+                markdown += "[";
+            }
+            ("a", true) => markdown += &format!("]({})", link),
+            _ => {}
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    return markdown;
+}
+
+// only the puzzle articles are kept, the rest of the page is navigation and answer forms
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let end = match rest[start..].find("</article>") {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        let content_start = match rest[start..end].find('>') {
+            Some(content_start) => start + content_start + 1,
+            None => break,
+        };
+
+        markdown += &convert_article(&rest[content_start..end]);
+        rest = &rest[end..];
+    }
+
+    return markdown.trim_end().to_string() + "\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn test_page() -> String {
+        return match fs::read_to_string("../utils/data/testPuzzle.html") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to read puzzle page. {}", e);
+            }
+        };
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(&test_page());
+
+        assert!(markdown.starts_with("## --- Day 1: Test Lists ---\n\n"));
+        assert!(markdown.contains("```\n3   4\n4   3\n2   5\n```\n"));
+        assert!(markdown.contains("would be `11`."));
+        assert!(markdown.contains("Using the **same** lists"));
+        assert!(!markdown.contains("1319616"));
+    }
+
+    #[test]
+    fn test_get_page() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to start the test server. {}", e);
+            }
+        };
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().map(|a| a.to_string()).unwrap_or_default()
+        );
+        let page = test_page();
+
+        // a one request stand-in for the site that only answers with the session cookie
+        let server = thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                let mut line = String::new();
+
+                while reader.read_line(&mut line).is_ok() && line != "\r\n" && !line.is_empty() {
+                    request += &line;
+                    line.clear();
+                }
+
+                let (status, body) = if request.starts_with("GET /2024/day/1 ") && request.contains("session=test") {
+                    ("200 OK", page)
+                } else {
+                    ("400 Bad Request", String::new())
+                };
+
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let cache_path = std::env::temp_dir()
+            .join(format!("aoc-read-{}", std::process::id()))
+            .join("puzzle.html")
+            .to_string_lossy()
+            .to_string();
+
+        let fetched = match get_page(&base_url, Some("test"), Puzzle::new(2024, 1), &cache_path, false) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to fetch puzzle page. {}", e);
+            }
+        };
+        let _ = server.join();
+
+        // the stand-in is gone, so this has to come from the cache
+        let cached = get_page(&base_url, Some("test"), Puzzle::new(2024, 1), &cache_path, false).ok();
+
+        assert!(fetched.contains("--- Part Two ---"));
+        assert_eq!(cached, Some(fetched));
+
+        // a page with only part one in it is not fetched again until asked to
+        let part_one = "<article><h2>--- Day 1: Test Lists ---</h2></article>";
+        let _ = fs::write(&cache_path, part_one);
+        assert_eq!(
+            get_page(&base_url, Some("test"), Puzzle::new(2024, 1), &cache_path, false).ok(),
+            Some(part_one.to_string())
+        );
+        assert!(get_page(&base_url, Some("test"), Puzzle::new(2024, 1), &cache_path, true).is_err());
+
+        if let Some(directory) = Path::new(&cache_path).parent() {
+            let _ = fs::remove_dir_all(directory);
+        }
+    }
+}
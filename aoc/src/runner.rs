@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use utils::generate::Request;
+use utils::puzzle::Puzzle;
+
+// every numbered directory in the workspace is a year, every day-NN inside it a solution
+pub fn get_years(workspace: &str) -> Result<Vec<u32>, Box<dyn Error>> {
+    let mut years: Vec<u32> = Vec::new();
+
+    for entry in fs::read_dir(workspace)? {
+        let entry = entry?;
+        if let Ok(year) = entry.file_name().to_string_lossy().parse::<u32>() {
+            if entry.path().is_dir() {
+                years.push(year);
+            }
+        }
+    }
+
+    years.sort();
+    return Ok(years);
+}
+
+pub fn get_days(workspace: &str, year: u32) -> Vec<u32> {
+    return (1..=25)
+        .filter(|&day| Path::new(&format!("{}/{}", workspace, Puzzle::new(year, day).directory())).exists())
+        .collect();
+}
+
+// days print one "label: answer" line per part, some only print the answer, a "part N" label puts
+// the answer in that part's place and any part skipped before it is left empty
+pub fn parse_answers(output: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut answers: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("Error:") {
+            return Err(line.into());
+        }
+
+        let (label, answer) = match line.rsplit_once(": ") {
+            Some((label, answer)) => (label, answer),
+            None => ("", line),
+        };
+
+        if let Some(Ok(part)) = label.strip_prefix("part ").map(str::parse::<usize>) {
+            if part > answers.len() {
+                answers.resize(part - 1, String::new());
+            }
+        }
+
+        answers.push(answer.to_string());
+    }
+
+    return Ok(answers);
+}
+
+// the day runs from its own directory, so a relative --render or --svg path has to be made absolute
+// first
+fn resolve_output_paths(args: &[String]) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut resolved: Vec<String> = Vec::new();
+
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 && (args[i - 1] == "--render" || args[i - 1] == "--svg") && Path::new(arg).is_relative() {
+            resolved.push(std::env::current_dir()?.join(arg).to_string_lossy().to_string());
+        } else {
+            resolved.push(arg.clone());
+        }
+    }
+
+    return Ok(resolved);
+}
+
+// extra args go to the day binary, -v / -vv need its trace feature and --alloc its counting allocator,
+// --render its image encoders
+pub fn run_day(workspace: &str, puzzle: Puzzle, args: &[String]) -> Result<Vec<String>, Box<dyn Error>> {
+    let name = puzzle.crate_name();
+    let directory = format!("{}/{}", workspace, puzzle.directory());
+
+    if !Path::new(&directory).exists() {
+        return Err(format!("{} does not exist", name).into());
+    }
+
+    let mut command = Command::new("cargo");
+    command.args(["run", "--release", "-q", "-p", &name]);
+
+    let mut features: Vec<&str> = Vec::new();
+    if args.iter().any(|arg| arg == "-v" || arg == "-vv") {
+        features.push("trace");
+    }
+    if args.iter().any(|arg| arg == "--alloc") {
+        features.push("alloc");
+    }
+    if args.iter().any(|arg| arg == "--render") {
+        features.push("render");
+    }
+
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+    command.arg("--").args(resolve_output_paths(args)?);
+
+    let output = command.current_dir(&directory).output()?;
+
+    if !output.status.success() {
+        return Err(format!("{} failed: {}", name, String::from_utf8_lossy(&output.stderr)).into());
+    }
+
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    return parse_answers(&String::from_utf8_lossy(&output.stdout));
+}
+
+// the player needs the terminal to itself so nothing is captured, only days with an animate
+// feature have a simulation to play
+pub fn animate_day(workspace: &str, puzzle: Puzzle) -> Result<(), Box<dyn Error>> {
+    let name = puzzle.crate_name();
+    let directory = format!("{}/{}", workspace, puzzle.directory());
+
+    if !Path::new(&directory).exists() {
+        return Err(format!("{} does not exist", name).into());
+    }
+
+    let status = Command::new("cargo")
+        .args([
+            "run",
+            "--release",
+            "-q",
+            "-p",
+            &name,
+            "--features",
+            "animate",
+            "--",
+            "--animate",
+        ])
+        .current_dir(&directory)
+        .status()?;
+
+    if !status.success() {
+        return Err(format!("{} failed, it may not have a simulation", name).into());
+    }
+
+    return Ok(());
+}
+
+// the day checks its own input with --validate and prints one line per problem
+pub fn validate_day(workspace: &str, puzzle: Puzzle) -> Result<Vec<String>, Box<dyn Error>> {
+    let name = puzzle.crate_name();
+    let directory = format!("{}/{}", workspace, puzzle.directory());
+
+    if !Path::new(&directory).exists() {
+        return Err(format!("{} does not exist", name).into());
+    }
+
+    let output = Command::new("cargo")
+        .args(["run", "--release", "-q", "-p", &name, "--", "--validate"])
+        .current_dir(&directory)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!("{} failed: {}", name, String::from_utf8_lossy(&output.stderr)).into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if let Some(error) = stdout.lines().find(|line| line.starts_with("Error:")) {
+        return Err(error.into());
+    }
+
+    return Ok(stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty() && *line != "no problems found")
+        .map(String::from)
+        .collect());
+}
+
+// the day writes the files itself so its generator and parser always agree on the format, what it
+// wrote is reported on stderr which is passed straight through
+pub fn generate_day(workspace: &str, puzzle: Puzzle, request: &Request) -> Result<(), Box<dyn Error>> {
+    let name = puzzle.crate_name();
+    let day_directory = format!("{}/{}", workspace, puzzle.directory());
+
+    if !Path::new(&day_directory).exists() {
+        return Err(format!("{} does not exist", name).into());
+    }
+
+    // the day runs from its own directory so a relative one has to be resolved first
+    let directory = std::env::current_dir()?.join(&request.directory);
+    let request = Request {
+        directory: directory.to_string_lossy().to_string(),
+        ..request.clone()
+    };
+
+    let output = Command::new("cargo")
+        .args(["run", "--release", "-q", "-p", &name, "--"])
+        .args(request.args())
+        .current_dir(&day_directory)
+        .output()?;
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    // days without a generator ignore --generate and just solve their input
+    if !output.status.success() || !directory.exists() {
+        return Err(format!("{} failed, it may not have a generator", name).into());
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let output = "minimum distance: 11\nsimilarity score: 31\n";
+        let answers = match parse_answers(output) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to parse answers. {}", e);
+            }
+        };
+
+        assert_eq!(answers, vec!["11", "31"]);
+        assert_eq!(parse_answers("12\n").ok(), Some(vec!["12".to_string()]));
+        assert!(parse_answers("Error: Failed to retrieve map. missing").is_err());
+        assert_eq!(
+            parse_answers("part 2: 1796\n").ok(),
+            Some(vec![String::new(), "1796".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_get_days() {
+        let years = match get_years("..") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve years. {}", e);
+            }
+        };
+
+        assert!(years.contains(&2024));
+        assert_eq!(get_days("..", 2024)[..3], [1, 2, 3]);
+        assert!(get_days("..", 1999).is_empty());
+    }
+}
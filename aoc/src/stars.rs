@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+// each year gets its own section in the README, between these markers
+fn readme_markers(year: u32) -> (String, String) {
+    return (
+        format!("<!-- stars:{}:start -->", year),
+        format!("<!-- stars:{}:end -->", year),
+    );
+}
+
+const ROW_HEIGHT: u32 = 24;
+const GOLD: &str = "#ffff66";
+const SILVER: &str = "#9999cc";
+const MISSING: &str = "#333340";
+
+pub fn render_chart(stars: &BTreeMap<u32, u32>) -> String {
+    let height = ROW_HEIGHT * 25 + 16;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"160\" height=\"{}\" font-family=\"monospace\" font-size=\"16\">\n",
+        height
+    );
+    svg += "<rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>\n";
+
+    for day in 1..=25 {
+        let count = stars.get(&day).copied().unwrap_or(0);
+        let y = ROW_HEIGHT * day;
+
+        // the puzzle site draws a single star in silver and a finished day in gold
+        let colour = match count {
+            0 => MISSING,
+            1 => SILVER,
+            _ => GOLD,
+        };
+
+        svg += &format!(
+            "<text x=\"40\" y=\"{}\" fill=\"#cccccc\" text-anchor=\"end\">{}</text>\n",
+            y, day
+        );
+
+        for star in 0..2 {
+            let fill = if star < count { colour } else { MISSING };
+            svg += &format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\">*</text>\n",
+                64 + star * 16,
+                y,
+                fill
+            );
+        }
+    }
+
+    svg += "</svg>\n";
+    return svg;
+}
+
+pub fn render_readme_section(year: u32, stars: &BTreeMap<u32, u32>, chart_path: &str) -> String {
+    let (start, end) = readme_markers(year);
+    let total: u32 = stars.values().sum();
+    let finished = stars.values().filter(|&&count| count >= 2).count();
+
+    let mut section = format!("{}\n![stars collected in {}]({})\n\n", start, year, chart_path);
+    section += &format!("{} stars collected, {} days finished\n\n", total, finished);
+    section += "| day | stars |\n| --- | --- |\n";
+
+    for (day, count) in stars {
+        section += &format!("| {} | {} |\n", day, "★".repeat(*count as usize));
+    }
+
+    section += &end;
+    return section;
+}
+
+pub fn update_readme(readme: &str, year: u32, section: &str) -> Result<String, Box<dyn Error>> {
+    let (start_marker, end_marker) = readme_markers(year);
+
+    let start = match readme.find(&start_marker) {
+        Some(index) => index,
+        None => return Err(format!("README is missing the {} marker", start_marker).into()),
+    };
+
+    let end = match readme[start..].find(&end_marker) {
+        Some(index) => start + index + end_marker.len(),
+        None => return Err(format!("README is missing the {} marker", end_marker).into()),
+    };
+
+    return Ok(format!("{}{}{}", &readme[..start], section, &readme[end..]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_stars() -> BTreeMap<u32, u32> {
+        let mut stars: BTreeMap<u32, u32> = BTreeMap::new();
+        stars.insert(1, 2);
+        stars.insert(2, 1);
+        return stars;
+    }
+
+    #[test]
+    fn test_render_chart() {
+        let svg = render_chart(&test_stars());
+
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches(&format!("fill=\"{}\">*", GOLD)).count(), 2);
+        assert_eq!(svg.matches(&format!("fill=\"{}\">*", SILVER)).count(), 1);
+        assert_eq!(svg.matches(&format!("fill=\"{}\">*", MISSING)).count(), 47);
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme = match std::fs::read_to_string("data/test/README.md") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to read README. {}", e);
+            }
+        };
+
+        let section = render_readme_section(2024, &test_stars(), "stars.svg");
+        let updated = match update_readme(&readme, 2024, &section) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to update README. {}", e);
+            }
+        };
+
+        assert!(!updated.contains("old section"));
+        assert!(updated.contains("3 stars collected, 1 days finished"));
+        assert!(updated.contains("| 2 | ★ |"));
+        assert!(updated.ends_with("<!-- stars:2024:end -->\n\n# footer\n"));
+    }
+}
@@ -0,0 +1,222 @@
+use std::error::Error;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use utils::get_csv_data;
+use utils::puzzle::Puzzle;
+
+const DAY_SECONDS: u64 = 24 * 60 * 60;
+
+const ROW_HEIGHT: u64 = 24;
+const BAR_WIDTH: u64 = 480;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveTime {
+    pub day: u32,
+    pub part: u32,
+    pub fetched: Option<u64>,
+    pub solved: Option<u64>,
+}
+
+pub fn now() -> u64 {
+    return match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_) => 0,
+    };
+}
+
+fn parse_timestamp(value: &str) -> Result<Option<u64>, Box<dyn Error>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    return Ok(Some(value.parse::<u64>()?));
+}
+
+pub fn get_times(path: &str) -> Result<Vec<SolveTime>, Box<dyn Error>> {
+    let mut times: Vec<SolveTime> = Vec::new();
+
+    if !Path::new(path).exists() {
+        return Ok(times);
+    }
+
+    let rows: Vec<Vec<String>> = get_csv_data(path, true)?;
+
+    for row in rows {
+        if row.len() < 4 {
+            return Err("times row has fewer than 4 columns".into());
+        }
+
+        times.push(SolveTime {
+            day: row[0].parse::<u32>()?,
+            part: row[1].parse::<u32>()?,
+            fetched: parse_timestamp(&row[2])?,
+            solved: parse_timestamp(&row[3])?,
+        });
+    }
+
+    return Ok(times);
+}
+
+pub fn save_times(path: &str, times: &[SolveTime]) -> Result<(), Box<dyn Error>> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(["day", "part", "fetched", "solved"])?;
+
+    for time in times {
+        writer.write_record([
+            time.day.to_string(),
+            time.part.to_string(),
+            time.fetched.map(|t| t.to_string()).unwrap_or_default(),
+            time.solved.map(|t| t.to_string()).unwrap_or_default(),
+        ])?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+fn get_entry(times: &mut Vec<SolveTime>, day: u32, part: u32) -> &mut SolveTime {
+    if !times.iter().any(|time| time.day == day && time.part == part) {
+        times.push(SolveTime {
+            day,
+            part,
+            fetched: None,
+            solved: None,
+        });
+        times.sort_by_key(|time| (time.day, time.part));
+    }
+
+    let index = times
+        .iter()
+        .position(|time| time.day == day && time.part == part)
+        .unwrap_or(0);
+
+    return &mut times[index];
+}
+
+// only the first fetch and the first correct answer are kept, later runs don't move them
+pub fn record_fetched(times: &mut Vec<SolveTime>, day: u32, part: u32, timestamp: u64) -> bool {
+    let entry = get_entry(times, day, part);
+    if entry.fetched.is_some() || entry.solved.is_some() {
+        return false;
+    }
+
+    entry.fetched = Some(timestamp);
+    return true;
+}
+
+pub fn record_solved(times: &mut Vec<SolveTime>, day: u32, part: u32, timestamp: u64) -> bool {
+    let entry = get_entry(times, day, part);
+    if entry.solved.is_some() {
+        return false;
+    }
+
+    entry.solved = Some(timestamp);
+    return true;
+}
+
+pub fn format_duration(seconds: u64) -> String {
+    if seconds >= DAY_SECONDS {
+        return ">24h".to_string();
+    }
+
+    return format!("{:02}:{:02}:{:02}", seconds / 3600, (seconds / 60) % 60, seconds % 60);
+}
+
+pub fn render_chart(year: u32, times: &[SolveTime]) -> String {
+    let days: u32 = times.iter().map(|time| time.day).max().unwrap_or(0);
+    let height = ROW_HEIGHT * (days as u64 * 2 + 2);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"14\">\n",
+        BAR_WIDTH + 200,
+        height
+    );
+    svg += "<rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>\n";
+    svg += "<text x=\"8\" y=\"20\" fill=\"#cccccc\">day</text>\n";
+    svg += "<text x=\"80\" y=\"20\" fill=\"#9999cc\">part 1</text>\n";
+    svg += "<text x=\"160\" y=\"20\" fill=\"#ffff66\">part 2</text>\n";
+
+    for day in 1..=days {
+        let unlock = Puzzle::new(year, day).unlock_time();
+        let y = ROW_HEIGHT * (day as u64 * 2);
+        svg += &format!("<text x=\"8\" y=\"{}\" fill=\"#cccccc\">{}</text>\n", y + 16, day);
+
+        for time in times.iter().filter(|time| time.day == day) {
+            let bar_y = y + (time.part as u64 - 1) * (ROW_HEIGHT - 4);
+            let colour = if time.part == 1 { "#9999cc" } else { "#ffff66" };
+
+            let solved = match time.solved {
+                Some(solved) => solved.saturating_sub(unlock),
+                None => continue,
+            };
+            let width = solved.min(DAY_SECONDS) * BAR_WIDTH / DAY_SECONDS;
+
+            svg += &format!(
+                "<rect x=\"80\" y=\"{}\" width=\"{}\" height=\"16\" fill=\"{}\"/>\n",
+                bar_y + 4,
+                width.max(1),
+                colour
+            );
+            svg += &format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>\n",
+                88 + width,
+                bar_y + 17,
+                colour,
+                format_duration(solved)
+            );
+
+            // the tick shows when the input was first fetched
+            if let Some(fetched) = time.fetched {
+                let offset = fetched.saturating_sub(unlock).min(DAY_SECONDS) * BAR_WIDTH / DAY_SECONDS;
+                svg += &format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"2\" height=\"20\" fill=\"#00cc00\"/>\n",
+                    80 + offset,
+                    bar_y + 2
+                );
+            }
+        }
+    }
+
+    svg += "</svg>\n";
+    return svg;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_times() {
+        let mut times = match get_times("data/test/times.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve times. {}", e);
+            }
+        };
+
+        assert_eq!(times.len(), 3);
+        assert!(!record_fetched(&mut times, 1, 1, 0));
+        assert!(record_solved(&mut times, 1, 2, 1733040000));
+        assert!(!record_solved(&mut times, 1, 2, 1733050000));
+        assert!(record_fetched(&mut times, 3, 1, 1733202000));
+
+        assert_eq!(times[1].solved, Some(1733040000));
+        assert_eq!(times[3].day, 3);
+    }
+
+    #[test]
+    fn test_render_chart() {
+        let times = match get_times("data/test/times.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve times. {}", e);
+            }
+        };
+
+        let svg = render_chart(2024, &times);
+        assert!(svg.contains(">01:00:00</text>"));
+        assert!(svg.contains(">23:59:59</text>"));
+        assert_eq!(svg.matches("fill=\"#00cc00\"").count(), 1);
+    }
+}
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::path::Path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::notify::RecursiveMode;
+use utils::puzzle::Puzzle;
+
+use crate::cache::hash_paths;
+use crate::runner::run_day;
+
+// editors write a file in a few steps, so changes are collected for a moment before rerunning
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+// reads the "test name ... ok" lines cargo test prints for every test
+pub fn parse_tests(output: &str) -> TestSummary {
+    let mut summary = TestSummary {
+        passed: 0,
+        failed: Vec::new(),
+    };
+
+    for line in output.lines() {
+        let line = match line.strip_prefix("test ") {
+            Some(line) => line,
+            None => continue,
+        };
+
+        if let Some((name, result)) = line.rsplit_once(" ... ") {
+            match result {
+                "ok" => summary.passed += 1,
+                "FAILED" => summary.failed.push(name.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    return summary;
+}
+
+// one line per part, new parts and changed answers show what they were before
+pub fn diff_answers(previous: &[String], current: &[String]) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for (i, answer) in current.iter().enumerate() {
+        let line = match previous.get(i) {
+            Some(before) if before == answer => format!("part {}: {}", i + 1, answer),
+            Some(before) => format!("part {}: {} (was {})", i + 1, answer, before),
+            None => format!("part {}: {} (new)", i + 1, answer),
+        };
+
+        lines.push(line);
+    }
+
+    return lines;
+}
+
+fn run_tests(workspace: &str, puzzle: Puzzle) -> Result<TestSummary, Box<dyn Error>> {
+    let output = Command::new("cargo")
+        .args(["test", "-p", &puzzle.crate_name(), "--color", "never"])
+        .current_dir(format!("{}/{}", workspace, puzzle.directory()))
+        .output()?;
+
+    let summary = parse_tests(&String::from_utf8_lossy(&output.stdout));
+
+    // a build error leaves no test lines behind, so the compiler output is the useful part
+    if !output.status.success() && summary.failed.is_empty() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim_end().into());
+    }
+
+    return Ok(summary);
+}
+
+fn rerun(workspace: &str, puzzle: Puzzle, previous: &mut Vec<String>) {
+    match run_tests(workspace, puzzle) {
+        Ok(summary) if summary.failed.is_empty() => println!("tests: {} passed", summary.passed),
+        Ok(summary) => println!(
+            "tests: {} passed, {} failed ({})",
+            summary.passed,
+            summary.failed.len(),
+            summary.failed.join(", ")
+        ),
+        Err(e) => {
+            println!("build failed:\n{}", e);
+            return;
+        }
+    }
+
+    match run_day(workspace, puzzle, &[]) {
+        Ok(answers) => {
+            for line in diff_answers(previous, &answers) {
+                println!("{}", line);
+            }
+            *previous = answers;
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+pub fn watch(workspace: &str, puzzle: Puzzle) -> Result<(), Box<dyn Error>> {
+    let directory = format!("{}/{}", workspace, puzzle.directory());
+    if !Path::new(&directory).exists() {
+        return Err(format!("{} does not exist", puzzle.crate_name()).into());
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE, sender)?;
+
+    let watched = [format!("{}/src", directory), format!("{}/data", directory)];
+    for path in &watched {
+        if Path::new(path).exists() {
+            debouncer.watcher().watch(Path::new(path), RecursiveMode::Recursive)?;
+        }
+    }
+
+    // reading the inputs shows up as events too, so only a change in what the files hold reruns the day
+    let mut contents = hash_paths(&watched)?;
+    let mut previous: Vec<String> = Vec::new();
+    println!("watching {}/src and {}/data", puzzle.directory(), puzzle.directory());
+    rerun(workspace, puzzle, &mut previous);
+
+    for events in receiver {
+        match events {
+            Ok(events) if events.is_empty() => continue,
+            Ok(_) => {
+                let changed = hash_paths(&watched)?;
+                if changed == contents {
+                    continue;
+                }
+
+                contents = changed;
+                println!();
+                rerun(workspace, puzzle, &mut previous);
+            }
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tests() {
+        let output = "running 3 tests\ntest tests::test_part_one ... ok\ntest tests::test_part_two ... FAILED\ntest tests::test_parse ... ok\n\ntest result: FAILED. 2 passed; 1 failed; 0 ignored\n";
+
+        assert_eq!(
+            parse_tests(output),
+            TestSummary {
+                passed: 2,
+                failed: vec!["tests::test_part_two".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = vec!["11".to_string()];
+        let current = vec!["11".to_string(), "31".to_string()];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec!["part 1: 11", "part 2: 31 (new)"]
+        );
+        assert_eq!(diff_answers(&current, &previous[..0]), Vec::<String>::new());
+        assert_eq!(
+            diff_answers(&["12".to_string()], &previous),
+            vec!["part 1: 11 (was 12)"]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// allocation counting for the day crates, the allocator is only installed with the alloc
+// feature (cargo run --features alloc) so normal runs keep the system allocator untouched
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+
+    let in_use = IN_USE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    // a realloc counts as a fresh allocation of the new size, the old block is released
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+
+        return new_ptr;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Snapshot {
+    pub allocations: usize,
+    pub allocated: usize,
+    pub in_use: usize,
+}
+
+pub fn snapshot() -> Snapshot {
+    return Snapshot {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+        in_use: IN_USE.load(Ordering::Relaxed),
+    };
+}
+
+// starts a new peak window at whatever is in use right now
+pub fn reset_peak() {
+    PEAK.store(IN_USE.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+// the most bytes this allocator had handed out at once, not the resident size of the process
+pub fn peak() -> usize {
+    return PEAK.load(Ordering::Relaxed);
+}
+
+// the high water mark of the process's resident memory, stack, mapped files and the allocator's own
+// overhead included, read from VmHWM in /proc/self/status so only linux has one
+pub fn peak_resident() -> Option<usize> {
+    return parse_peak_resident(&std::fs::read_to_string("/proc/self/status").ok()?);
+}
+
+fn parse_peak_resident(status: &str) -> Option<usize> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes = line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim();
+
+    return Some(kilobytes.parse::<usize>().ok()? * 1024);
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        return format!("{} B", bytes);
+    }
+
+    return format!("{:.1} {}", value, units[unit]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_peak_resident() {
+        assert_eq!(
+            parse_peak_resident("VmPeak:\t  12000 kB\nVmHWM:\t    2048 kB\nVmRSS:\t    1024 kB\n"),
+            Some(2048 * 1024)
+        );
+        assert_eq!(parse_peak_resident("VmRSS:\t1024 kB\n"), None);
+
+        if cfg!(target_os = "linux") {
+            assert!(peak_resident().is_some_and(|bytes| bytes > 0));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_snapshot() {
+        let before = snapshot();
+        reset_peak();
+
+        let data: Vec<u64> = vec![0; 1024];
+        let after = snapshot();
+
+        assert!(after.allocations > before.allocations);
+        assert!(after.allocated - before.allocated >= 8 * 1024);
+        assert!(peak() >= 8 * 1024);
+        assert_eq!(data.len(), 1024);
+    }
+}
@@ -0,0 +1,258 @@
+// fixed size bit sets for visited tracking, a BitGrid is a BitSet laid out row by row with an
+// optional number of planes per cell (one per direction for "been here facing this way")
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    pub fn new(len: usize) -> BitSet {
+        return BitSet {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        };
+    }
+
+    // true when the bit was not already set, indexes past len are never set so they add nothing
+    pub fn insert(&mut self, index: usize) -> bool {
+        if index >= self.len {
+            return false;
+        }
+
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+
+        return added;
+    }
+
+    // true when the bit was set
+    pub fn remove(&mut self, index: usize) -> bool {
+        if index >= self.len {
+            return false;
+        }
+
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let removed = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+
+        return removed;
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        return index < self.len && self.words[index / 64] & (1 << (index % 64)) != 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    // how many bits are set
+    pub fn count(&self) -> usize {
+        return self.words.iter().map(|word| word.count_ones() as usize).sum();
+    }
+
+    // the set bits in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        return self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        });
+    }
+
+    // how many bits it can hold, not how many are set
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BitGrid {
+    bits: BitSet,
+    rows: usize,
+    cols: usize,
+    planes: usize,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, cols: usize) -> BitGrid {
+        return BitGrid::with_planes(rows, cols, 1);
+    }
+
+    pub fn with_planes(rows: usize, cols: usize, planes: usize) -> BitGrid {
+        return BitGrid {
+            bits: BitSet::new(rows * cols * planes),
+            rows,
+            cols,
+            planes,
+        };
+    }
+
+    fn index(&self, (row, col): (usize, usize), plane: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.cols || plane >= self.planes {
+            return None;
+        }
+
+        return Some((row * self.cols + col) * self.planes + plane);
+    }
+
+    // the plain versions use plane 0, nothing outside the grid is ever inserted or contained, so a
+    // wrapped around spot can be tested without checking it first
+    pub fn insert(&mut self, spot: (usize, usize)) -> bool {
+        return self.insert_on(spot, 0);
+    }
+
+    pub fn insert_on(&mut self, spot: (usize, usize), plane: usize) -> bool {
+        return match self.index(spot, plane) {
+            Some(index) => self.bits.insert(index),
+            None => false,
+        };
+    }
+
+    pub fn remove(&mut self, spot: (usize, usize)) -> bool {
+        return self.remove_on(spot, 0);
+    }
+
+    pub fn remove_on(&mut self, spot: (usize, usize), plane: usize) -> bool {
+        return match self.index(spot, plane) {
+            Some(index) => self.bits.remove(index),
+            None => false,
+        };
+    }
+
+    pub fn contains(&self, spot: (usize, usize)) -> bool {
+        return self.contains_on(spot, 0);
+    }
+
+    pub fn contains_on(&self, spot: (usize, usize), plane: usize) -> bool {
+        return match self.index(spot, plane) {
+            Some(index) => self.bits.contains(index),
+            None => false,
+        };
+    }
+
+    // set on any plane
+    pub fn contains_any(&self, spot: (usize, usize)) -> bool {
+        return (0..self.planes).any(|plane| self.contains_on(spot, plane));
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    // bits set over every plane
+    pub fn count(&self) -> usize {
+        return self.bits.count();
+    }
+
+    // cells with at least one plane set
+    pub fn count_cells(&self) -> usize {
+        return self.cells().count();
+    }
+
+    // every set bit as ((row, col), plane), row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), usize)> + '_ {
+        return self.bits.iter().map(|index| {
+            let cell = index / self.planes;
+            ((cell / self.cols, cell % self.cols), index % self.planes)
+        });
+    }
+
+    // every cell with at least one plane set, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut last: Option<(usize, usize)> = None;
+
+        return self.iter().filter_map(move |(spot, _)| {
+            if last == Some(spot) {
+                return None;
+            }
+
+            last = Some(spot);
+            Some(spot)
+        });
+    }
+
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+
+    pub fn planes(&self) -> usize {
+        return self.planes;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_set() {
+        let mut set = BitSet::new(130);
+
+        assert!(set.insert(0));
+        assert!(set.insert(64));
+        assert!(set.insert(129));
+        assert!(!set.insert(64));
+        assert!(set.contains(129));
+        assert!(!set.contains(130));
+        assert!(!set.insert(130));
+        assert!(!set.remove(usize::MAX));
+        assert_eq!(set.count(), 3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 64, 129]);
+
+        assert!(set.remove(64));
+        assert!(!set.remove(64));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 129]);
+
+        set.clear();
+        assert_eq!(set.count(), 0);
+        assert_eq!(set.len(), 130);
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::with_planes(3, 4, 4);
+
+        assert!(grid.insert_on((1, 2), 3));
+        assert!(grid.insert_on((1, 2), 0));
+        assert!(!grid.insert_on((1, 2), 3));
+        assert!(grid.insert((2, 3)));
+        assert!(grid.contains((1, 2)));
+        assert!(!grid.contains_on((1, 2), 1));
+        assert!(grid.contains_any((2, 3)));
+        assert!(!grid.contains((0, usize::MAX)));
+        assert!(!grid.contains((3, 0)));
+        assert!(!grid.insert((3, 0)));
+        assert!(!grid.insert_on((0, 0), 4));
+
+        assert_eq!(grid.count(), 3);
+        assert_eq!(grid.count_cells(), 2);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![((1, 2), 0), ((1, 2), 3), ((2, 3), 0)]
+        );
+        assert_eq!(grid.cells().collect::<Vec<_>>(), vec![(1, 2), (2, 3)]);
+
+        assert!(grid.remove_on((1, 2), 0));
+        assert!(!grid.remove((0, 9)));
+        assert_eq!(grid.cells().collect::<Vec<_>>(), vec![(1, 2), (2, 3)]);
+    }
+}
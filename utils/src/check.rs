@@ -0,0 +1,147 @@
+// checks a fast solver against a slow but obviously right one on lots of small random puzzles,
+// built on proptest so a disagreement (or a panic in either) gets shrunk down to the smallest
+// puzzle that still shows it, days pull this in as a dev dependency with the check feature
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+pub use proptest;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+// rows x cols cells picked by weight, shrinking heads for the first choice and the smallest size
+pub fn grid(
+    rows: RangeInclusive<usize>,
+    cols: RangeInclusive<usize>,
+    cells: &[(u32, char)],
+) -> BoxedStrategy<Vec<String>> {
+    let cell = Union::new_weighted(cells.iter().map(|(weight, cell)| (*weight, Just(*cell))).collect());
+
+    return (rows, cols)
+        .prop_flat_map(move |(rows, cols)| vec(vec(cell.clone(), cols), rows))
+        .prop_map(|grid| grid.iter().map(|row| row.iter().collect()).collect())
+        .boxed();
+}
+
+// swaps one cell of every grid for the marker, for the guard or robot a puzzle starts from
+pub fn place(grid: BoxedStrategy<Vec<String>>, marker: char) -> BoxedStrategy<Vec<String>> {
+    return grid
+        .prop_flat_map(|grid| {
+            let (rows, cols) = (grid.len().max(1), grid.first().map_or(1, |row| row.len().max(1)));
+            (Just(grid), 0..rows, 0..cols)
+        })
+        .prop_map(move |(mut grid, row, col)| {
+            if let Some(line) = grid.get_mut(row) {
+                *line = line
+                    .chars()
+                    .enumerate()
+                    .map(|(j, cell)| if j == col { marker } else { cell })
+                    .collect();
+            }
+            grid
+        })
+        .boxed();
+}
+
+// a line of digits, the dense puzzle inputs like a disk map
+pub fn digits(len: RangeInclusive<usize>, digits: RangeInclusive<u8>) -> BoxedStrategy<String> {
+    return vec(digits, len)
+        .prop_map(|digits| digits.iter().map(|digit| char::from(b'0' + digit % 10)).collect())
+        .boxed();
+}
+
+// PROPTEST_CASES still sets how many puzzles are tried, failures are not saved to disk since the
+// shrunk puzzle is printed in full
+fn config() -> Config {
+    return Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+}
+
+// Err holds the smallest puzzle the two disagree on along with both answers
+pub fn agree<S, T, R, O>(strategy: S, reference: R, optimised: O) -> Result<(), String>
+where
+    S: Strategy,
+    S::Value: Debug,
+    T: PartialEq + Debug,
+    R: Fn(&S::Value) -> T,
+    O: Fn(&S::Value) -> T,
+{
+    let result = TestRunner::new(config()).run(&strategy, |puzzle| {
+        let (expected, actual) = (reference(&puzzle), optimised(&puzzle));
+
+        if expected != actual {
+            return Err(TestCaseError::fail(format!(
+                "reference gave {:?} but optimised gave {:?}",
+                expected, actual
+            )));
+        }
+
+        return Ok(());
+    });
+
+    return match result {
+        Ok(()) => Ok(()),
+        Err(TestError::Fail(reason, puzzle)) => Err(format!("{}, smallest puzzle:\n{:#?}", reason, puzzle)),
+        Err(e) => Err(e.to_string()),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators() {
+        let mut runner = TestRunner::new(config());
+        let strategy = place(grid(2..=3, 4..=4, &[(1, '.'), (1, '#')]), '^');
+
+        for _ in 0..50 {
+            let puzzle = match strategy.new_tree(&mut runner) {
+                Ok(tree) => tree.current(),
+                Err(e) => panic!("Error: Failed to generate a grid in test_generators. {}", e),
+            };
+
+            assert!((2..=3).contains(&puzzle.len()));
+            assert!(puzzle
+                .iter()
+                .all(|row| row.len() == 4 && row.chars().all(|cell| ".#^".contains(cell))));
+            assert_eq!(puzzle.concat().matches('^').count(), 1);
+        }
+
+        let line = match digits(5..=5, 1..=3).new_tree(&mut runner) {
+            Ok(tree) => tree.current(),
+            Err(e) => panic!("Error: Failed to generate digits in test_generators. {}", e),
+        };
+        assert!(line.len() == 5 && line.chars().all(|digit| ('1'..='3').contains(&digit)));
+    }
+
+    #[test]
+    fn test_agree() {
+        let sum = |line: &String| line.chars().filter_map(|digit| digit.to_digit(10)).sum::<u32>();
+        // forgets about the sevens
+        let broken = |line: &String| {
+            line.chars()
+                .filter_map(|digit| digit.to_digit(10))
+                .filter(|digit| *digit != 7)
+                .sum::<u32>()
+        };
+
+        assert_eq!(agree(digits(0..=20, 0..=9), sum, sum), Ok(()));
+
+        match agree(digits(0..=20, 0..=9), sum, broken) {
+            Ok(()) => panic!("Error: Failed to catch the missing sevens in test_agree."),
+            Err(e) => assert!(e.ends_with("smallest puzzle:\n\"7\""), "{}", e),
+        }
+
+        // a panic counts as a disagreement too, the one puzzle tried always divides by zero
+        match agree(Just("7".to_string()), sum, |line: &String| sum(line) / (sum(line) - 7)) {
+            Ok(()) => panic!("Error: Failed to catch the panic in test_agree."),
+            Err(e) => assert_eq!(e, "attempt to divide by zero, smallest puzzle:\n\"7\""),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// union-find over 0..n with path compression and union by size, plus connected component
+// labelling for grids
+
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(size: usize) -> DisjointSet {
+        return DisjointSet {
+            parents: (0..size).collect(),
+            sizes: vec![1; size],
+            sets: size,
+        };
+    }
+
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // point everything on the way straight at the root
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        return root;
+    }
+
+    // false when both were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.sets -= 1;
+
+        return true;
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        return self.find(a) == self.find(b);
+    }
+
+    // the size of the set the element is in
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        return self.sizes[root];
+    }
+
+    pub fn len(&self) -> usize {
+        return self.parents.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.parents.is_empty();
+    }
+
+    pub fn set_count(&self) -> usize {
+        return self.sets;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Components {
+    // labels[row][column], labels are numbered from 0 in the order their first cell is found
+    pub labels: Vec<Vec<usize>>,
+    pub sizes: Vec<usize>,
+}
+
+// cells join their right and lower neighbours whenever same says so, rows can be ragged
+pub fn label_grid<T, F>(grid: &[Vec<T>], mut same: F) -> Components
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let offsets: Vec<usize> = grid
+        .iter()
+        .scan(0, |offset, row| {
+            let start = *offset;
+            *offset += row.len();
+            Some(start)
+        })
+        .collect();
+    let mut set = DisjointSet::new(grid.iter().map(|row| row.len()).sum());
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            if j + 1 < row.len() && same(cell, &row[j + 1]) {
+                set.union(offsets[i] + j, offsets[i] + j + 1);
+            }
+
+            if let Some(below) = grid.get(i + 1).and_then(|below| below.get(j)) {
+                if same(cell, below) {
+                    set.union(offsets[i] + j, offsets[i + 1] + j);
+                }
+            }
+        }
+    }
+
+    let mut label_of_root: Vec<Option<usize>> = vec![None; set.len()];
+    let mut components = Components {
+        labels: Vec::new(),
+        sizes: Vec::new(),
+    };
+
+    for (i, row) in grid.iter().enumerate() {
+        let mut labels: Vec<usize> = Vec::new();
+
+        for j in 0..row.len() {
+            let root = set.find(offsets[i] + j);
+            let label = match label_of_root[root] {
+                Some(label) => label,
+                None => {
+                    label_of_root[root] = Some(components.sizes.len());
+                    components.sizes.push(set.size(root));
+                    components.sizes.len() - 1
+                }
+            };
+
+            labels.push(label);
+        }
+
+        components.labels.push(labels);
+    }
+
+    return components;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.same(0, 3));
+        assert!(!set.same(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+        assert_eq!(set.set_count(), 3);
+    }
+
+    #[test]
+    fn test_label_grid() {
+        let grid: Vec<Vec<char>> = ["AAB", "ABB", "CCB"].iter().map(|row| row.chars().collect()).collect();
+        let components = label_grid(&grid, |a, b| a == b);
+
+        assert_eq!(components.labels, vec![vec![0, 0, 1], vec![0, 1, 1], vec![2, 2, 1]]);
+        assert_eq!(components.sizes, vec![3, 4, 2]);
+    }
+}
@@ -0,0 +1,208 @@
+// pulls the worked examples out of a saved puzzle page, the example input is a <pre><code> block and
+// the answer for it is the last <code><em> in that part's article
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::get_csv_data;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub part: u32,
+    pub block: usize,
+    pub input: String,
+    pub answer: String,
+}
+
+pub fn unescape_html(text: &str) -> String {
+    return text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    return unescape_html(&stripped);
+}
+
+// every (position, inner text) between open and close
+fn find_all(text: &str, open: &str, close: &str) -> Vec<(usize, String)> {
+    let mut found: Vec<(usize, String)> = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find(open) {
+        let content_start = offset + start + open.len();
+
+        let end = match text[content_start..].find(close) {
+            Some(end) => content_start + end,
+            None => break,
+        };
+
+        found.push((offset + start, strip_tags(&text[content_start..end])));
+        offset = end + close.len();
+    }
+
+    return found;
+}
+
+pub fn get_code_blocks(html: &str) -> Vec<String> {
+    return find_all(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect();
+}
+
+pub fn get_examples(html: &str) -> Vec<Example> {
+    let blocks = find_all(html, "<pre><code>", "</code></pre>");
+    let articles = find_all(html, "<article", "</article>");
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (i, (article_start, _)) in articles.iter().enumerate() {
+        let article_end = match html[*article_start..].find("</article>") {
+            Some(end) => article_start + end,
+            None => continue,
+        };
+
+        let answer = find_all(&html[*article_start..article_end], "<code><em>", "</em></code>")
+            .into_iter()
+            .next_back()
+            .map(|(position, answer)| (article_start + position, answer));
+
+        // part two usually reuses the example from part one, so the block can come from an earlier article
+        if let Some((position, answer)) = answer {
+            if let Some(block) = blocks.iter().rposition(|(start, _)| *start < position) {
+                examples.push(Example {
+                    part: i as u32 + 1,
+                    block: block + 1,
+                    input: blocks[block].1.clone(),
+                    answer,
+                });
+            }
+        }
+    }
+
+    return examples;
+}
+
+// writes data/example-N.csv into the day's directory and upserts day,part,file,answer rows into
+// the expectations file
+pub fn write_examples(
+    examples: &[Example],
+    day: u32,
+    day_directory: &str,
+    expectations_path: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut expectations: Vec<Vec<String>> = Vec::new();
+
+    if Path::new(expectations_path).exists() {
+        expectations = get_csv_data(expectations_path, true)?;
+    }
+
+    fs::create_dir_all(format!("{}/data", day_directory))?;
+
+    for example in examples {
+        let file = format!("data/example-{}.csv", example.block);
+        fs::write(format!("{}/{}", day_directory, file), &example.input)?;
+
+        expectations.retain(|row| row.len() < 2 || row[0] != day.to_string() || row[1] != example.part.to_string());
+        expectations.push(vec![
+            day.to_string(),
+            example.part.to_string(),
+            file,
+            example.answer.clone(),
+        ]);
+    }
+
+    expectations.sort_by_key(|row| {
+        (
+            row.first().and_then(|day| day.parse::<u32>().ok()),
+            row.get(1).and_then(|part| part.parse::<u32>().ok()),
+        )
+    });
+
+    let mut writer = csv::Writer::from_path(expectations_path)?;
+    writer.write_record(["day", "part", "file", "answer"])?;
+    for row in expectations {
+        writer.write_record(row)?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_page() -> String {
+        return match fs::read_to_string("data/testPuzzle.html") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to read puzzle page. {}", e);
+            }
+        };
+    }
+
+    #[test]
+    fn test_get_examples() {
+        let examples = get_examples(&test_page());
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "3   4\n4   3\n2   5\n");
+        assert_eq!(examples[0].answer, "11");
+        assert_eq!(examples[1].block, 1);
+        assert_eq!(examples[1].answer, "31");
+        assert_eq!(unescape_html("a &lt; b &amp;&amp; b &gt; c"), "a < b && b > c");
+    }
+
+    #[test]
+    fn test_write_examples() {
+        let directory = std::env::temp_dir().join(format!("utils-examples-{}", std::process::id()));
+        let day_directory = directory.join("day-01").to_string_lossy().to_string();
+        let expectations_path = directory.join("examples.csv").to_string_lossy().to_string();
+
+        if let Err(e) = fs::create_dir_all(&directory) {
+            panic!("Error: Failed to create test directory. {}", e);
+        }
+
+        let examples = get_examples(&test_page());
+        for _ in 0..2 {
+            if let Err(e) = write_examples(&examples, 1, &day_directory, &expectations_path) {
+                panic!("Error: Failed to write examples. {}", e);
+            }
+        }
+
+        let expectations: Vec<Vec<String>> = match get_csv_data(&expectations_path, true) {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve expectations. {}", e);
+            }
+        };
+
+        assert_eq!(
+            expectations,
+            vec![
+                vec!["1", "1", "data/example-1.csv", "11"],
+                vec!["1", "2", "data/example-1.csv", "31"]
+            ]
+        );
+        assert!(Path::new(&format!("{}/data/example-1.csv", day_directory)).exists());
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,268 @@
+// seeded random puzzle inputs in the same format as the real ones, for stress tests and benchmarks
+// (cargo run -p aoc -- 2024 gen 6 --seed 1 --size 40), every day has a generate function that
+// turns a seed and a size (and a width and height for day 14's arena) into the files that normally
+// live in its data directory
+
+use std::error::Error;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+// splitmix64, small and random enough for puzzles, the same seed gives the same puzzle everywhere
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return z ^ (z >> 31);
+    }
+
+    // both ends included, an empty range gives its start
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = (*range.start(), *range.end());
+        if end <= start {
+            return start;
+        }
+
+        let span = (end as i128 - start as i128 + 1) as u128;
+        return (start as i128 + (self.next_u64() as u128 % span) as i128) as i64;
+    }
+
+    // 0..n, or 0 when n is 0
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+
+        return (self.next_u64() % n as u64) as usize;
+    }
+
+    pub fn chance(&mut self, percent: u32) -> bool {
+        return self.below(100) < percent as usize;
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        return items.get(self.below(items.len()));
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub directory: String,
+    pub seed: u64,
+    pub size: Option<usize>,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+}
+
+// --generate <directory> [--seed N] [--size N] [--width N] [--height N], a number that does not
+// parse is left at its default
+pub fn requested() -> Option<Request> {
+    return parse(&std::env::args().collect::<Vec<String>>());
+}
+
+pub fn parse(args: &[String]) -> Option<Request> {
+    let value = |flag: &str| {
+        let position = args.iter().position(|arg| arg == flag)?;
+        return args.get(position + 1).cloned();
+    };
+    let number = |flag: &str| value(flag).and_then(|number| number.parse().ok());
+
+    return Some(Request {
+        directory: value("--generate")?,
+        seed: value("--seed").and_then(|seed| seed.parse().ok()).unwrap_or(0),
+        size: number("--size"),
+        width: number("--width"),
+        height: number("--height"),
+    });
+}
+
+impl Request {
+    pub fn rng(&self) -> Rng {
+        return Rng::new(self.seed);
+    }
+
+    pub fn size_or(&self, default: usize) -> usize {
+        return self.size.unwrap_or(default);
+    }
+
+    pub fn width_or(&self, default: usize) -> usize {
+        return self.width.unwrap_or(default);
+    }
+
+    pub fn height_or(&self, default: usize) -> usize {
+        return self.height.unwrap_or(default);
+    }
+
+    // the flags parse reads back, for handing the request on to a day binary
+    pub fn args(&self) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "--generate".to_string(),
+            self.directory.clone(),
+            "--seed".to_string(),
+            self.seed.to_string(),
+        ];
+
+        for (flag, number) in [
+            ("--size", self.size),
+            ("--width", self.width),
+            ("--height", self.height),
+        ] {
+            if let Some(number) = number {
+                args.extend([flag.to_string(), number.to_string()]);
+            }
+        }
+
+        return args;
+    }
+
+    // each file is named the way it is under data, input.csv or input/map.csv
+    pub fn save(&self, files: &[(&str, String)]) -> Result<(), Box<dyn Error>> {
+        for path in write(Path::new(&self.directory), files)? {
+            eprintln!("wrote {}", path.display());
+        }
+
+        return Ok(());
+    }
+}
+
+pub fn write(directory: &Path, files: &[(&str, String)]) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut written: Vec<PathBuf> = Vec::new();
+
+    for (name, contents) in files {
+        let path = directory.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    return Ok(written);
+}
+
+// somewhere for tests to write a generated puzzle so the day's own parser can read it back
+pub fn scratch(name: &str, files: &[(&str, String)]) -> Result<String, Box<dyn Error>> {
+    let directory = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+    write(&directory, files)?;
+
+    return Ok(directory.to_string_lossy().to_string());
+}
+
+// writes a puzzle to a scratch directory, reads it back with the day's own loader and cleans up,
+// for the days' generate and validate tests, the loader is handed the input the way main hands it
+// data/input.csv, or the input directory when the files are under input/
+pub fn read_back<T>(
+    name: &str,
+    files: &[(&str, String)],
+    read: impl FnOnce(&str) -> Result<T, Box<dyn Error>>,
+) -> Result<T, Box<dyn Error>> {
+    let directory = scratch(name, files)?;
+    let input = files
+        .first()
+        .and_then(|(file, _)| file.split('/').next())
+        .unwrap_or_default();
+    let result = read(&format!("{}/{}", directory, input));
+    let _ = std::fs::remove_dir_all(&directory);
+
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let numbers: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+
+        assert_eq!(numbers, (0..5).map(|_| b.next_u64()).collect::<Vec<u64>>());
+        assert_ne!(numbers, (0..5).map(|_| Rng::new(8).next_u64()).collect::<Vec<u64>>());
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&a.range(-3..=3)));
+            assert!(a.below(10) < 10);
+        }
+        assert_eq!(a.range(5..=5), 5);
+        assert_eq!(a.below(0), 0);
+        assert!(a.range(i64::MIN..=i64::MAX) != a.range(i64::MIN..=i64::MAX));
+        assert_eq!(a.pick::<u32>(&[]), None);
+
+        let mut items: Vec<u32> = (0..20).collect();
+        a.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<u32>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_request() {
+        let request = Request {
+            directory: "data/generated/seed-3".to_string(),
+            seed: 3,
+            size: None,
+            width: Some(11),
+            height: Some(7),
+        };
+
+        assert_eq!(
+            request.args().join(" "),
+            "--generate data/generated/seed-3 --seed 3 --width 11 --height 7"
+        );
+        assert_eq!(parse(&request.args()), Some(request.clone()));
+        assert_eq!((request.size_or(500), request.width_or(101)), (500, 11));
+        assert_eq!(parse(&["--seed".to_string(), "3".to_string()]), None);
+    }
+
+    #[test]
+    fn test_write() {
+        let directory = match scratch(
+            "utils-generate",
+            &[("input.csv", "1,2\n".to_string()), ("input/map.csv", "#\n".to_string())],
+        ) {
+            Ok(result) => result,
+            Err(e) => panic!("Error: Failed to write files in test_write. {}", e),
+        };
+
+        let input = std::fs::read_to_string(format!("{}/input.csv", directory)).unwrap_or_default();
+        let map = std::fs::read_to_string(format!("{}/input/map.csv", directory)).unwrap_or_default();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        assert_eq!(input, "1,2\n");
+        assert_eq!(map, "#\n");
+
+        let read = |path: &str| Ok(std::fs::read_to_string(path)?);
+        assert_eq!(
+            read_back("utils-generate-read", &[("input.csv", "3\n".to_string())], read).ok(),
+            Some("3\n".to_string())
+        );
+        assert_eq!(
+            read_back(
+                "utils-generate-split",
+                &[("input/map.csv", "#\n".to_string())],
+                |path| read(&format!("{}/map.csv", path))
+            )
+            .ok(),
+            Some("#\n".to_string())
+        );
+        assert!(read_back("utils-generate-missing", &[], read).is_err());
+    }
+}
@@ -0,0 +1,230 @@
+// 2d points and the vectors between them, x is the column and y is the row so y points down the
+// same way it does in every grid we read in
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::math::gcd;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        return Point { x, y };
+    }
+
+    // (row, col) as used for indexing a Vec<Vec<T>>
+    pub fn from_index((row, col): (usize, usize)) -> Point {
+        return Point::new(col as i64, row as i64);
+    }
+
+    // (row, col) when the point is inside a rows x cols grid
+    pub fn index(self, rows: usize, cols: usize) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.y).ok().filter(|row| *row < rows)?;
+        let col = usize::try_from(self.x).ok().filter(|col| *col < cols)?;
+
+        return Some((row, col));
+    }
+
+    pub fn get<T>(self, grid: &[Vec<T>]) -> Option<&T> {
+        let row = grid.get(usize::try_from(self.y).ok()?)?;
+        return row.get(usize::try_from(self.x).ok()?);
+    }
+
+    pub fn get_mut<T>(self, grid: &mut [Vec<T>]) -> Option<&mut T> {
+        let row = grid.get_mut(usize::try_from(self.y).ok()?)?;
+        return row.get_mut(usize::try_from(self.x).ok()?);
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        return (other - self).manhattan();
+    }
+
+    // wraps around a width x height area the way the puzzles teleport things off one edge onto the
+    // other
+    pub fn rem_euclid(self, width: i64, height: i64) -> Point {
+        return Point::new(self.x.rem_euclid(width), self.y.rem_euclid(height));
+    }
+
+    pub fn neighbours(self) -> [Point; 4] {
+        return Vector::DIRECTIONS.map(|direction| self + direction);
+    }
+}
+
+impl Vector {
+    pub const UP: Vector = Vector::new(0, -1);
+    pub const DOWN: Vector = Vector::new(0, 1);
+    pub const LEFT: Vector = Vector::new(-1, 0);
+    pub const RIGHT: Vector = Vector::new(1, 0);
+
+    // clockwise from up
+    pub const DIRECTIONS: [Vector; 4] = [Vector::UP, Vector::RIGHT, Vector::DOWN, Vector::LEFT];
+
+    pub const fn new(x: i64, y: i64) -> Vector {
+        return Vector { x, y };
+    }
+
+    pub fn manhattan(self) -> i64 {
+        return self.x.abs() + self.y.abs();
+    }
+
+    // clockwise on screen, so up turns to the right
+    pub fn rotate_right(self) -> Vector {
+        return Vector::new(-self.y, self.x);
+    }
+
+    pub fn rotate_left(self) -> Vector {
+        return Vector::new(self.y, -self.x);
+    }
+
+    // the smallest whole step along the same line, the zero vector stays zero
+    pub fn reduced(self) -> Vector {
+        return match gcd(self.x, self.y) {
+            Some(0) => self,
+            Some(divisor) => Vector::new(self.x / divisor, self.y / divisor),
+            // only i64::MIN and 0 have a gcd too big for an i64
+            None => Vector::new(self.x.signum(), self.y.signum()),
+        };
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, other: Vector) -> Point {
+        return Point::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, other: Vector) -> Point {
+        return Point::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        return Vector::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        return Vector::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        return Vector::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scale: i64) -> Vector {
+        return Vector::new(self.x * scale, self.y * scale);
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        return Vector::new(-self.x, -self.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(2, 3);
+        let b = Point::new(-1, 7);
+
+        assert_eq!(b - a, Vector::new(-3, 4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a + Vector::RIGHT * 3, Point::new(5, 3));
+        assert_eq!(a - -Vector::UP, Point::new(2, 2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(Point::new(-1, 9).rem_euclid(11, 7), Point::new(10, 2));
+        assert_eq!(Vector::new(-6, 4).reduced(), Vector::new(-3, 2));
+        assert_eq!(Vector::default().reduced(), Vector::default());
+        assert_eq!(Vector::new(i64::MIN, 0).reduced(), Vector::new(-1, 0));
+        assert_eq!(Vector::new(i64::MIN, -4).reduced(), Vector::new(i64::MIN / 4, -1));
+
+        let mut c = a;
+        c += Vector::DOWN;
+        c -= Vector::LEFT;
+        assert_eq!(c, Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Vector::UP.rotate_right(), Vector::RIGHT);
+        assert_eq!(Vector::RIGHT.rotate_right(), Vector::DOWN);
+        assert_eq!(Vector::UP.rotate_left(), Vector::LEFT);
+
+        for (i, direction) in Vector::DIRECTIONS.iter().enumerate() {
+            assert_eq!(direction.rotate_right(), Vector::DIRECTIONS[(i + 1) % 4]);
+            assert_eq!(direction.rotate_right().rotate_left(), *direction);
+        }
+    }
+
+    #[test]
+    fn test_grid_index() {
+        let mut grid = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+
+        assert_eq!(Point::from_index((1, 2)), Point::new(2, 1));
+        assert_eq!(Point::new(2, 1).index(2, 3), Some((1, 2)));
+        assert_eq!(Point::new(3, 1).index(2, 3), None);
+        assert_eq!(Point::new(-1, 0).index(2, 3), None);
+        assert_eq!(Point::new(1, 1).get(&grid), Some(&'e'));
+        assert_eq!(Point::new(0, -1).get(&grid), None);
+
+        if let Some(cell) = Point::new(0, 1).get_mut(&mut grid) {
+            *cell = 'z';
+        }
+        assert_eq!(grid[1][0], 'z');
+        assert_eq!(
+            Point::new(0, 0)
+                .neighbours()
+                .iter()
+                .filter(|spot| spot.get(&grid).is_some())
+                .count(),
+            2
+        );
+    }
+}
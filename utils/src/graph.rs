@@ -0,0 +1,475 @@
+// searches over any hashable node type, neighbours come from a closure so grids and other
+// implicit graphs work without building them first, adjacency lists go through adjacent / weighted
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub fn adjacent(list: &[Vec<usize>]) -> impl Fn(&usize) -> Vec<usize> + '_ {
+    return move |node| list.get(*node).cloned().unwrap_or_default();
+}
+
+pub fn weighted(list: &[Vec<(usize, u64)>]) -> impl Fn(&usize) -> Vec<(usize, u64)> + '_ {
+    return move |node| list.get(*node).cloned().unwrap_or_default();
+}
+
+// steps from start to every reachable node
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances: HashMap<N, usize> = HashMap::new();
+    let mut queue: VecDeque<(N, usize)> = VecDeque::new();
+
+    distances.insert(start.clone(), 0);
+    queue.push_back((start, 0));
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    return distances;
+}
+
+// the shortest path to the first node that satisfies goal, start and goal included
+pub fn bfs_path<N, F, G, I>(start: N, mut neighbours: F, mut goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            let mut path = vec![node];
+            while let Some(Some(parent)) = path.last().and_then(|node| parents.get(node)) {
+                path.push(parent.clone());
+            }
+
+            path.reverse();
+            return Some(path);
+        }
+
+        for next in neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    return None;
+}
+
+// every reachable node in depth first preorder, neighbours are visited in the order they are given
+pub fn dfs<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut order: Vec<N> = Vec::new();
+    let mut stack: Vec<N> = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+
+        let next: Vec<N> = neighbours(&node).into_iter().collect();
+        stack.extend(next.into_iter().rev().filter(|next| !visited.contains(next)));
+        order.push(node);
+    }
+
+    return order;
+}
+
+// nodes get an id the first time they are seen so the heap only has to order numbers
+struct Search<N> {
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn id(&mut self, node: &N) -> usize {
+        if let Some(id) = self.ids.get(node) {
+            return *id;
+        }
+
+        self.ids.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node.clone());
+        self.costs.push(u64::MAX);
+        self.parents.push(None);
+
+        return self.nodes.len() - 1;
+    }
+
+    fn path(&self, id: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[id].clone()];
+        let mut current = id;
+
+        while let Some(parent) = self.parents[current] {
+            path.push(self.nodes[parent].clone());
+            current = parent;
+        }
+
+        path.reverse();
+        return path;
+    }
+}
+
+// dijkstra when the heuristic is always 0, A* otherwise, the heuristic must never overestimate
+fn best_first<N, F, H, G, I>(start: N, mut neighbours: F, mut heuristic: H, mut goal: G) -> (Search<N>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search {
+        nodes: Vec::new(),
+        ids: HashMap::new(),
+        costs: Vec::new(),
+        parents: Vec::new(),
+    };
+    let mut heap: BinaryHeap<Reverse<(u64, u64, usize)>> = BinaryHeap::new();
+
+    let start_id = search.id(&start);
+    search.costs[start_id] = 0;
+    heap.push(Reverse((heuristic(&start), 0, start_id)));
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        if cost > search.costs[id] {
+            continue;
+        }
+
+        let node = search.nodes[id].clone();
+        if goal(&node) {
+            return (search, Some(id));
+        }
+
+        for (next, weight) in neighbours(&node) {
+            let next_cost = cost + weight;
+            let next_id = search.id(&next);
+
+            if next_cost < search.costs[next_id] {
+                search.costs[next_id] = next_cost;
+                search.parents[next_id] = Some(id);
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next_id)));
+            }
+        }
+    }
+
+    return (search, None);
+}
+
+// lowest cost from start to every reachable node
+pub fn dijkstra<N, F, I>(start: N, neighbours: F) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (search, _) = best_first(start, neighbours, |_| 0, |_| false);
+
+    return search
+        .nodes
+        .into_iter()
+        .zip(search.costs)
+        .filter(|(_, cost)| *cost != u64::MAX)
+        .collect();
+}
+
+pub fn dijkstra_path<N, F, G, I>(start: N, neighbours: F, goal: G) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    return astar(start, neighbours, |_| 0, goal);
+}
+
+pub fn astar<N, F, H, G, I>(start: N, neighbours: F, heuristic: H, goal: G) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (search, found) = best_first(start, neighbours, heuristic, goal);
+    return found.map(|id| (search.costs[id], search.path(id)));
+}
+
+// every node before the nodes it points at, nodes that are not related keep their given order,
+// a cycle comes back as the error with its nodes in order
+pub fn toposort<N, F, I>(nodes: &[N], mut neighbours: F) -> Result<Vec<N>, Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    // false while the node is on the current path, true once everything after it is placed
+    let mut finished: HashMap<N, bool> = HashMap::new();
+    let mut order: Vec<N> = Vec::new();
+
+    for root in nodes.iter().rev() {
+        if finished.contains_key(root) {
+            continue;
+        }
+
+        finished.insert(root.clone(), false);
+        let mut stack: Vec<(N, Vec<N>)> = vec![(root.clone(), neighbours(root).into_iter().collect())];
+
+        while let Some((_, pending)) = stack.last_mut() {
+            match pending.pop() {
+                Some(next) => match finished.get(&next) {
+                    Some(true) => {}
+                    Some(false) => {
+                        let start = stack.iter().position(|(node, _)| *node == next).unwrap_or(0);
+                        return Err(stack[start..].iter().map(|(node, _)| node.clone()).collect());
+                    }
+                    None => {
+                        finished.insert(next.clone(), false);
+                        let pending = neighbours(&next).into_iter().collect();
+                        stack.push((next, pending));
+                    }
+                },
+                None => {
+                    if let Some((node, _)) = stack.pop() {
+                        finished.insert(node.clone(), true);
+                        order.push(node);
+                    }
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    return Ok(order);
+}
+
+// tarjan's algorithm without recursion, components come out in reverse topological order
+pub fn strongly_connected_components<N, F, I>(nodes: &[N], mut neighbours: F) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    // ids are handed out in visiting order, so an id doubles as the tarjan index
+    let mut ids: HashMap<N, usize> = HashMap::new();
+    let mut found: Vec<N> = Vec::new();
+    let mut low: Vec<usize> = Vec::new();
+    let mut on_stack: Vec<bool> = Vec::new();
+    let mut stack: Vec<usize> = Vec::new();
+    let mut components: Vec<Vec<N>> = Vec::new();
+
+    for root in nodes {
+        if ids.contains_key(root) {
+            continue;
+        }
+
+        let mut calls: Vec<(usize, Vec<N>)> = Vec::new();
+        let mut visit = Some(root.clone());
+
+        loop {
+            if let Some(node) = visit.take() {
+                let id = found.len();
+                ids.insert(node.clone(), id);
+                low.push(id);
+                on_stack.push(true);
+                stack.push(id);
+
+                let mut pending: Vec<N> = neighbours(&node).into_iter().collect();
+                pending.reverse();
+                found.push(node);
+                calls.push((id, pending));
+            }
+
+            let (id, next) = match calls.last_mut() {
+                Some((id, pending)) => (*id, pending.pop()),
+                None => break,
+            };
+
+            match next {
+                Some(next) => match ids.get(&next) {
+                    Some(next_id) if on_stack[*next_id] => low[id] = low[id].min(*next_id),
+                    Some(_) => {}
+                    None => visit = Some(next),
+                },
+                None => {
+                    calls.pop();
+                    if let Some((parent, _)) = calls.last() {
+                        low[*parent] = low[*parent].min(low[id]);
+                    }
+
+                    if low[id] == id {
+                        let mut component: Vec<N> = Vec::new();
+                        while let Some(member) = stack.pop() {
+                            on_stack[member] = false;
+                            component.push(found[member].clone());
+                            if member == id {
+                                break;
+                            }
+                        }
+
+                        component.reverse();
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+
+    return components;
+}
+
+// distinct paths from start to any node that satisfies goal, a path stops at the first goal it
+// reaches, the graph has to be acyclic so edges back onto the current path are skipped
+pub fn count_paths<N, F, G, I>(start: N, mut neighbours: F, mut goal: G) -> u64
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = N>,
+{
+    if goal(&start) {
+        return 1;
+    }
+
+    let mut counts: HashMap<N, u64> = HashMap::new();
+    let mut on_path: HashSet<N> = HashSet::new();
+
+    on_path.insert(start.clone());
+    let pending = neighbours(&start).into_iter().collect();
+    let mut stack: Vec<(N, Vec<N>, u64)> = vec![(start, pending, 0)];
+
+    while let Some((_, pending, _)) = stack.last_mut() {
+        let found = match pending.pop() {
+            Some(next) => {
+                if let Some(count) = counts.get(&next) {
+                    *count
+                } else if goal(&next) {
+                    counts.insert(next, 1);
+                    1
+                } else {
+                    if on_path.insert(next.clone()) {
+                        let pending = neighbours(&next).into_iter().collect();
+                        stack.push((next, pending, 0));
+                    }
+                    0
+                }
+            }
+            None => match stack.pop() {
+                Some((node, _, count)) => {
+                    on_path.remove(&node);
+                    if stack.is_empty() {
+                        return count;
+                    }
+
+                    counts.insert(node, count);
+                    count
+                }
+                None => 0,
+            },
+        };
+
+        if let Some((_, _, count)) = stack.last_mut() {
+            *count += found;
+        }
+    }
+
+    return 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 and 0 -> 2, with 4 <-> 5 off on its own
+    fn test_graph() -> Vec<Vec<usize>> {
+        return vec![vec![1, 2], vec![2], vec![3], vec![], vec![5], vec![4]];
+    }
+
+    fn grid_neighbours(grid: &[&str], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut next: Vec<(usize, usize)> = Vec::new();
+
+        for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+            let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+            if nx < 0 || ny < 0 || ny as usize >= grid.len() || nx as usize >= grid[0].len() {
+                continue;
+            }
+
+            if grid[ny as usize].as_bytes()[nx as usize] != b'#' {
+                next.push((nx as usize, ny as usize));
+            }
+        }
+
+        return next;
+    }
+
+    #[test]
+    fn test_searches() {
+        let graph = test_graph();
+
+        let distances = bfs(0, adjacent(&graph));
+        assert_eq!(distances.get(&3), Some(&2));
+        assert_eq!(distances.get(&4), None);
+
+        assert_eq!(bfs_path(0, adjacent(&graph), |node| *node == 3), Some(vec![0, 2, 3]));
+        assert_eq!(dfs(0, adjacent(&graph)), vec![0, 1, 2, 3]);
+        assert_eq!(count_paths(0, adjacent(&graph), |node| *node == 3), 2);
+
+        let grid = ["..#.", "..#.", "...."];
+        let path = bfs_path((0, 0), |node| grid_neighbours(&grid, *node), |node| *node == (3, 0));
+        assert_eq!(path.map(|path| path.len()), Some(8));
+    }
+
+    #[test]
+    fn test_weighted_searches() {
+        let graph = vec![vec![(1, 1), (2, 4)], vec![(2, 1), (3, 6)], vec![(3, 1)], vec![]];
+
+        let costs = dijkstra(0, weighted(&graph));
+        assert_eq!(costs.get(&3), Some(&3));
+
+        assert_eq!(
+            dijkstra_path(0, weighted(&graph), |node| *node == 3),
+            Some((3, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(
+            astar(0, weighted(&graph), |node| 3 - *node as u64, |node| *node == 3),
+            Some((3, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(dijkstra_path(3, weighted(&graph), |node| *node == 0), None);
+    }
+
+    #[test]
+    fn test_orderings() {
+        let graph = test_graph();
+
+        assert_eq!(toposort(&[0, 1, 2, 3], adjacent(&graph)), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(toposort(&[3, 2, 1, 0], adjacent(&graph)), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(toposort(&[0, 4], adjacent(&graph)), Err(vec![4, 5]));
+
+        let components = strongly_connected_components(&[0, 1, 2, 3, 4, 5], adjacent(&graph));
+        assert_eq!(components, vec![vec![3], vec![2], vec![1], vec![0], vec![4, 5]]);
+    }
+}
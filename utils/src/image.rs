@@ -0,0 +1,341 @@
+// turns simulation frames into pictures, every cell becomes a cell_size square in its kind's
+// colour, a single frame goes to a png and a run of them to an animated gif (the encoders are
+// behind the render feature, cargo run --features render -- --render out.gif)
+
+use std::error::Error;
+
+use crate::sim::{CellKind, Frame, Simulation};
+
+// one colour per kind, the six named kinds come first and then one for each CellKind::Shade a day
+// wants to tell apart, kinds the palette has no colour for are drawn as empty
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colours: Vec<[u8; 3]>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        return Palette::new(&[
+            [15, 15, 35],
+            [204, 204, 204],
+            [60, 110, 220],
+            [255, 255, 102],
+            [220, 50, 47],
+            [0, 153, 0],
+        ]);
+    }
+}
+
+// gif frames index into at most 256 colours
+const COLOURS: usize = 256;
+
+fn index(kind: CellKind) -> usize {
+    return match kind {
+        CellKind::Empty => 0,
+        CellKind::Wall => 1,
+        CellKind::Visited => 2,
+        CellKind::Item => 3,
+        CellKind::Actor => 4,
+        CellKind::Highlight => 5,
+        CellKind::Shade(shade) => 6 + shade as usize,
+    };
+}
+
+impl Palette {
+    // in index order, empty, wall, visited, item, actor, highlight and then the shades
+    pub fn new(colours: &[[u8; 3]]) -> Palette {
+        return Palette {
+            colours: colours.iter().take(COLOURS).copied().collect(),
+        };
+    }
+
+    // a palette with a different colour for one kind, the gap up to a new shade is left empty
+    pub fn with(mut self, kind: CellKind, colour: [u8; 3]) -> Palette {
+        let index = index(kind);
+        if index >= COLOURS {
+            return self;
+        }
+
+        if index >= self.colours.len() {
+            let empty = self.colour(CellKind::Empty);
+            self.colours.resize(index + 1, empty);
+        }
+        self.colours[index] = colour;
+
+        return self;
+    }
+
+    pub fn colour(&self, kind: CellKind) -> [u8; 3] {
+        return self
+            .colours
+            .get(self.index(kind) as usize)
+            .copied()
+            .unwrap_or([0, 0, 0]);
+    }
+
+    // where the kind's colour is in the palette, 0 (empty) when it has none
+    pub fn index(&self, kind: CellKind) -> u8 {
+        let index = index(kind);
+        if index < self.colours.len() {
+            return index as u8;
+        }
+
+        return 0;
+    }
+
+    // r, g, b for each colour in index order
+    pub fn flat(&self) -> Vec<u8> {
+        return self.colours.iter().flatten().copied().collect();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Renderer {
+    // pixels per side of a cell
+    pub cell_size: u32,
+    pub palette: Palette,
+    // hundredths of a second per gif frame
+    pub delay: u16,
+    // keep every nth step, long simulations make huge gifs otherwise
+    pub every: usize,
+    // stop after this many steps if the simulation never finishes
+    pub limit: usize,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        return Renderer {
+            cell_size: 4,
+            palette: Palette::default(),
+            delay: 5,
+            every: 1,
+            limit: 10_000,
+        };
+    }
+}
+
+impl Renderer {
+    // width and height in pixels, rows can be ragged so the widest one wins
+    pub fn size(&self, frame: &Frame) -> (u32, u32) {
+        let cols = frame.iter().map(|row| row.len()).max().unwrap_or(0) as u32;
+        return (cols * self.cell_size, frame.len() as u32 * self.cell_size);
+    }
+
+    // one byte per pixel, the index of its kind's colour in the palette, missing cells are empty
+    pub fn indexed(&self, frame: &Frame) -> Vec<u8> {
+        let (width, height) = self.size(frame);
+        let size = self.cell_size as usize;
+        let mut pixels: Vec<u8> = vec![0; width as usize * height as usize];
+
+        for (i, row) in frame.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                let index = self.palette.index(cell.kind);
+
+                for y in i * size..(i + 1) * size {
+                    let start = y * width as usize + j * size;
+                    pixels[start..start + size].fill(index);
+                }
+            }
+        }
+
+        return pixels;
+    }
+
+    // three bytes per pixel
+    pub fn rgb(&self, frame: &Frame) -> Vec<u8> {
+        return self
+            .indexed(frame)
+            .iter()
+            .flat_map(|index| self.palette.colours.get(*index as usize).copied().unwrap_or([0, 0, 0]))
+            .collect();
+    }
+}
+
+pub fn requested() -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let position = args.iter().position(|arg| arg == "--render")?;
+
+    return args.get(position + 1).cloned();
+}
+
+// --render <file> for a day, the simulation is only built when it was asked for, false when
+// writing failed and the day should stop
+pub fn render_requested<S: Simulation>(simulation: impl FnOnce() -> S, renderer: &Renderer) -> bool {
+    let path = match requested() {
+        Some(path) => path,
+        None => return true,
+    };
+
+    return match render(&mut simulation(), &path, renderer) {
+        Ok(frames) => {
+            eprintln!("wrote {} frames to {}", frames, path);
+            true
+        }
+        Err(e) => {
+            println!("Error: Failed to render {}. {}", path, e);
+            false
+        }
+    };
+}
+
+// a .gif gets every kept step, anything else gets the last frame as a png, returns how many
+// frames were written
+pub fn render<S: Simulation>(simulation: &mut S, path: &str, renderer: &Renderer) -> Result<usize, Box<dyn Error>> {
+    if !path.ends_with(".gif") {
+        let mut steps: usize = 0;
+        while !simulation.is_done() && steps < renderer.limit {
+            simulation.step();
+            steps += 1;
+        }
+
+        write_png(path, &simulation.render(), renderer)?;
+        return Ok(1);
+    }
+
+    let mut frames: Vec<Frame> = vec![simulation.render()];
+    let mut steps: usize = 0;
+
+    while !simulation.is_done() && steps < renderer.limit {
+        simulation.step();
+        steps += 1;
+
+        if steps.is_multiple_of(renderer.every.max(1)) || simulation.is_done() {
+            frames.push(simulation.render());
+        }
+    }
+
+    write_gif(path, &frames, renderer)?;
+    return Ok(frames.len());
+}
+
+#[cfg(feature = "render")]
+pub fn write_png(path: &str, frame: &Frame, renderer: &Renderer) -> Result<(), Box<dyn Error>> {
+    let (width, height) = renderer.size(frame);
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&renderer.rgb(frame))?;
+
+    return Ok(());
+}
+
+// every frame is sized after the first one
+#[cfg(feature = "render")]
+pub fn write_gif(path: &str, frames: &[Frame], renderer: &Renderer) -> Result<(), Box<dyn Error>> {
+    let (width, height) = match frames.first() {
+        Some(frame) => renderer.size(frame),
+        None => return Err("no frames to write".into()),
+    };
+    let (width, height) = (u16::try_from(width)?, u16::try_from(height)?);
+
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &renderer.palette.flat())?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in frames {
+        let mut pixels = renderer.indexed(frame);
+        pixels.resize(width as usize * height as usize, 0);
+
+        let mut image = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        image.delay = renderer.delay;
+        encoder.write_frame(&image)?;
+    }
+
+    return Ok(());
+}
+
+#[cfg(not(feature = "render"))]
+pub fn write_png(_path: &str, _frame: &Frame, _renderer: &Renderer) -> Result<(), Box<dyn Error>> {
+    return Err("built without the render feature, rerun with --features render".into());
+}
+
+#[cfg(not(feature = "render"))]
+pub fn write_gif(_path: &str, _frames: &[Frame], _renderer: &Renderer) -> Result<(), Box<dyn Error>> {
+    return Err("built without the render feature, rerun with --features render".into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::Cell;
+
+    #[test]
+    fn test_pixels() {
+        let frame: Frame = vec![
+            vec![Cell::new('#', CellKind::Wall), Cell::new('@', CellKind::Actor)],
+            vec![Cell::new('.', CellKind::Empty)],
+        ];
+        let renderer = Renderer {
+            cell_size: 2,
+            ..Renderer::default()
+        };
+
+        assert_eq!(renderer.size(&frame), (4, 4));
+        assert_eq!(
+            renderer.indexed(&frame),
+            vec![1, 1, 4, 4, 1, 1, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        let rgb = renderer.rgb(&frame);
+        assert_eq!(rgb.len(), 4 * 4 * 3);
+        assert_eq!(rgb[0..3], Palette::default().colour(CellKind::Wall));
+        assert_eq!(rgb[6..9], Palette::default().colour(CellKind::Actor));
+        assert_eq!(renderer.palette.flat().len(), 18);
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::default()
+            .with(CellKind::Wall, [1, 2, 3])
+            .with(CellKind::Shade(2), [9, 9, 9]);
+
+        assert_eq!(palette.colour(CellKind::Wall), [1, 2, 3]);
+        assert_eq!(palette.colour(CellKind::Shade(2)), [9, 9, 9]);
+        assert_eq!(palette.colour(CellKind::Shade(1)), palette.colour(CellKind::Empty));
+        assert_eq!(palette.index(CellKind::Shade(2)), 8);
+        assert_eq!(palette.index(CellKind::Shade(3)), 0);
+        assert_eq!(palette.flat().len(), 27);
+        assert_eq!(palette.clone().with(CellKind::Shade(250), [1, 1, 1]), palette);
+
+        let frame: Frame = vec![vec![
+            Cell::new('a', CellKind::Shade(2)),
+            Cell::new('b', CellKind::Shade(7)),
+        ]];
+        let renderer = Renderer {
+            cell_size: 1,
+            palette: Palette::new(&[[0, 0, 0], [255, 255, 255]]),
+            ..Renderer::default()
+        };
+
+        assert_eq!(renderer.indexed(&frame), vec![0, 0]);
+        assert_eq!(Renderer { palette, ..renderer }.rgb(&frame), vec![9, 9, 9, 15, 15, 35]);
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_write() {
+        let frame: Frame = vec![vec![Cell::new('@', CellKind::Actor); 3]; 2];
+        let directory = std::env::temp_dir().join(format!("utils-image-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap_or_default();
+
+        let png = directory.join("frame.png").to_string_lossy().to_string();
+        let gif = directory.join("frames.gif").to_string_lossy().to_string();
+        let renderer = Renderer::default();
+
+        match write_png(&png, &frame, &renderer).and_then(|_| write_gif(&gif, &[frame.clone(), frame], &renderer)) {
+            Ok(()) => {}
+            Err(e) => panic!("Error: Failed to write images in test_write. {}", e),
+        }
+
+        let png = std::fs::read(&png).unwrap_or_default();
+        let gif = std::fs::read(&gif).unwrap_or_default();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        assert_eq!(png[1..4], *b"PNG");
+        assert_eq!(gif[0..6], *b"GIF89a");
+    }
+}
@@ -1,3 +1,24 @@
+pub mod alloc;
+pub mod bits;
+#[cfg(feature = "check")]
+pub mod check;
+pub mod dsu;
+pub mod examples;
+pub mod generate;
+pub mod geom;
+pub mod graph;
+pub mod image;
+pub mod math;
+pub mod memo;
+pub mod puzzle;
+pub mod ranges;
+pub mod scan;
+pub mod sim;
+pub mod svg;
+pub mod trace;
+pub mod validate;
+pub mod variant;
+
 use std::str::FromStr;
 
 use csv::ReaderBuilder;
@@ -54,6 +75,17 @@ where
     return Ok(data);
 }
 
+// whole lines for inputs where commas are part of the text, blank lines are skipped like the csv reader does
+pub fn get_lines(path: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let text = std::fs::read_to_string(path)?;
+
+    return Ok(text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(String::from)
+        .collect());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +138,24 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_get_lines() {
+        let lines = match get_lines("data/testLines.csv") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to retrieve lines. {}", e);
+            }
+        };
+
+        assert_eq!(
+            lines,
+            vec![
+                "Button A: X+94, Y+34",
+                "Button B: X+22, Y+67",
+                "Prize: X=8400, Y=5400",
+                "p=0,4 v=3,-3"
+            ]
+        );
+    }
 }
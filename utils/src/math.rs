@@ -0,0 +1,280 @@
+// number theory over i64 and i128, anything that can overflow or has no answer returns an option
+// instead of panicking
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_rem_euclid(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_add(self, other);
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_sub(self, other);
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_mul(self, other);
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_div(self, other);
+                }
+
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_rem(self, other);
+                }
+
+                fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_rem_euclid(self, other);
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    return <$t>::checked_neg(self);
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    return <$t>::checked_abs(self);
+                }
+            }
+        )*
+    };
+}
+
+integer!(i64, i128);
+
+// always non-negative, gcd(0, 0) is 0, None only when the answer is -MIN (gcd(MIN, 0) or
+// gcd(MIN, MIN)), the loop runs on negatives because every positive has a negative but not the
+// other way round
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let negative = |n: T| if n > T::ZERO { -n } else { n };
+    let (mut a, mut b) = (negative(a), negative(b));
+
+    while b != T::ZERO {
+        // MIN % -1 is the only remainder that overflows and it is 0
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+
+    return a.checked_neg();
+}
+
+// None when it does not fit in T
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    return a.checked_div(gcd(a, b)?)?.checked_abs()?.checked_mul(b.checked_abs()?);
+}
+
+// (g, x, y) with a * x + b * y == g == gcd(a, b), None when any of them does not fit in T
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        // the last step only moves r and its coefficients down, skipping its quotient keeps
+        // MIN / -1 from overflowing
+        if old_r.checked_rem(r).unwrap_or(T::ZERO) == T::ZERO {
+            (old_r, old_x, old_y) = (r, x, y);
+            break;
+        }
+
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(quotient.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(quotient.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(quotient.checked_mul(y)?)?);
+    }
+
+    if old_r < T::ZERO {
+        return Some((old_r.checked_neg()?, old_x.checked_neg()?, old_y.checked_neg()?));
+    }
+
+    return Some((old_r, old_x, old_y));
+}
+
+// the x in 0..m with a * x = 1 (mod m), None unless a and m are coprime
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.checked_rem_euclid(m)?, m)?;
+    if g != T::ONE {
+        return None;
+    }
+
+    return x.checked_rem_euclid(m);
+}
+
+// x = residue (mod modulus) for every pair, returns the smallest non-negative x and the combined
+// modulus, moduli do not have to be coprime as long as the residues agree
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let (mut x, mut modulus) = (T::ZERO, T::ONE);
+
+    for &(residue, next_modulus) in congruences {
+        if next_modulus <= T::ZERO {
+            return None;
+        }
+
+        let residue = residue.checked_rem_euclid(next_modulus)?;
+        let (g, p, _) = extended_gcd(modulus, next_modulus)?;
+
+        let difference = residue.checked_sub(x)?;
+        let steps = div_exact(difference, g)?;
+
+        // x + modulus * k covers every solution so far, k = steps * p picks the one that also fits
+        let reduced = next_modulus / g;
+        let k = steps
+            .checked_rem_euclid(reduced)?
+            .checked_mul(p.checked_rem_euclid(reduced)?)?
+            .checked_rem_euclid(reduced)?;
+
+        let combined = modulus.checked_mul(reduced)?;
+        x = x.checked_add(modulus.checked_mul(k)?)?.checked_rem_euclid(combined)?;
+        modulus = combined;
+    }
+
+    return Some((x, modulus));
+}
+
+// a / b only when b divides a
+pub fn div_exact<T: Integer>(a: T, b: T) -> Option<T> {
+    return match a.checked_rem_euclid(b) {
+        Some(remainder) if remainder == T::ZERO => a.checked_div(b),
+        _ => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(gcd(12i64, -18), Some(6));
+        assert_eq!(gcd(0i64, 0), Some(0));
+        assert_eq!(lcm(101i64, 103), Some(10403));
+        assert_eq!(lcm(i64::MAX, i64::MAX - 1), None);
+        assert_eq!(extended_gcd(240i64, 46), Some((2, -9, 47)));
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(4i64, 8), None);
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1i64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(div_exact(94i128, 47), Some(2));
+        assert_eq!(div_exact(95i64, 47), None);
+        assert_eq!(div_exact(i64::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_min() {
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(gcd(i64::MIN, -1), Some(1));
+        assert_eq!(gcd(i64::MIN, 6), Some(2));
+        assert_eq!(gcd(i128::MIN, i128::MIN / 2), Some(-(i128::MIN / 2)));
+        assert_eq!(gcd(i128::MIN, 0), None);
+
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i64::MIN, 2), None);
+        assert_eq!(lcm(i64::MIN / 2, 2), Some(i64::MAX / 2 + 1));
+        assert_eq!(lcm(i128::MIN, i128::MIN), None);
+
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, -1), Some((1, 0, -1)));
+        assert_eq!(extended_gcd(i128::MIN, 3), Some((1, 1, i128::MAX / 3 + 1)));
+        assert_eq!(mod_inverse(i64::MIN, 3), Some(1));
+        assert_eq!(mod_inverse(3, i64::MIN), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_gcd_properties(a in -1_000_000_000i64..1_000_000_000, b in -1_000_000_000i64..1_000_000_000) {
+            let g = gcd(a, b).unwrap_or(0);
+            let (eg, x, y) = extended_gcd(a as i128, b as i128).unwrap_or((0, 0, 0));
+
+            prop_assert_eq!(g as i128, eg);
+            prop_assert_eq!(a as i128 * x + b as i128 * y, eg);
+
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), Some(1));
+
+                if let Some(l) = lcm(a, b) {
+                    prop_assert_eq!(l % a.abs(), 0);
+                    prop_assert_eq!(l % b.abs(), 0);
+                    prop_assert_eq!(l as i128 * g as i128, (a as i128 * b as i128).abs());
+                }
+            }
+        }
+
+        #[test]
+        fn test_mod_inverse_properties(a in -1_000_000i64..1_000_000, m in 1i64..1_000_000) {
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!((a * inverse).rem_euclid(m), 1 % m);
+                }
+                None => prop_assert_ne!(gcd(a, m), Some(1)),
+            }
+        }
+
+        #[test]
+        fn test_crt_properties(x in 0i128..1_000_000_000, moduli in prop::collection::vec(1i128..1_000, 1..5)) {
+            let congruences: Vec<(i128, i128)> = moduli.iter().map(|m| (x % m, *m)).collect();
+
+            match crt(&congruences) {
+                Some((solution, modulus)) => {
+                    prop_assert!(solution < modulus);
+                    prop_assert_eq!(solution, x % modulus);
+
+                    for (residue, m) in congruences {
+                        prop_assert_eq!(solution % m, residue);
+                        prop_assert_eq!(modulus % m, 0);
+                    }
+                }
+                None => prop_assert!(false, "consistent congruences have a solution"),
+            }
+        }
+
+        #[test]
+        fn test_div_exact_properties(a in any::<i64>(), b in any::<i64>()) {
+            match div_exact(a, b) {
+                Some(quotient) => prop_assert_eq!(quotient.checked_mul(b), Some(a)),
+                None => prop_assert!(b == 0 || a % b != 0 || a.checked_div(b).is_none()),
+            }
+        }
+    }
+}
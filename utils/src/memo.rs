@@ -0,0 +1,104 @@
+// caches the results of a pure recursive function by key, the function gets a recurse callback
+// in place of calling itself so every sub-problem goes through the cache:
+//
+//     let mut memo: Memo<u64, u64> = Memo::new();
+//     let fib = memo.get(80, &|recurse, n| if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) });
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        return Memo::new();
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        return Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        };
+    }
+
+    // the same function has to be passed for the same memo, the cache does not know which one
+    // filled it
+    pub fn get<F>(&mut self, key: K, function: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = function(&mut |next| self.get(next, function), key.clone());
+        self.cache.insert(key, value.clone());
+
+        return value;
+    }
+
+    // drops every cached result and starts the stats over
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        return MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memo() {
+        let mut memo: Memo<u64, u64> = Memo::new();
+        let fibonacci = |recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                recurse(n - 1) + recurse(n - 2)
+            }
+        };
+
+        assert_eq!(memo.get(80, &fibonacci), 23416728348467685);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 78,
+                misses: 81,
+                entries: 81
+            }
+        );
+
+        assert_eq!(memo.get(80, &fibonacci), 23416728348467685);
+        assert_eq!(memo.stats().hits, 79);
+
+        memo.clear();
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+}
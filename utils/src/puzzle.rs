@@ -0,0 +1,74 @@
+// a puzzle is one day of one year, the crate, data and site paths for it all come from here
+
+// puzzles unlock at midnight EST (UTC-5)
+const EST_OFFSET: i64 = 5 * 60 * 60;
+const DAY_SECONDS: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+}
+
+// days since 1970-01-01 for a date in the proleptic gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    return era * 146097 + day_of_era - 719468;
+}
+
+impl Puzzle {
+    pub fn new(year: u32, day: u32) -> Puzzle {
+        return Puzzle { year, day };
+    }
+
+    pub fn crate_name(&self) -> String {
+        return format!("y{}-day-{:02}", self.year, self.day);
+    }
+
+    // relative to the workspace root
+    pub fn directory(&self) -> String {
+        return format!("{}/day-{:02}", self.year, self.day);
+    }
+
+    // downloaded pages stay out of git, relative to the workspace root
+    pub fn cache_path(&self, file: &str) -> String {
+        return format!(".cache/{}/day-{:02}/{}", self.year, self.day, file);
+    }
+
+    // relative to the site root, e.g. /2024/day/6
+    pub fn url_path(&self) -> String {
+        return format!("/{}/day/{}", self.year, self.day);
+    }
+
+    pub fn unlock_time(&self) -> u64 {
+        let days = days_from_civil(self.year as i64, 12, self.day as i64);
+        return (days * DAY_SECONDS + EST_OFFSET) as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_paths() {
+        let puzzle = Puzzle::new(2024, 6);
+
+        assert_eq!(puzzle.crate_name(), "y2024-day-06");
+        assert_eq!(puzzle.directory(), "2024/day-06");
+        assert_eq!(puzzle.url_path(), "/2024/day/6");
+        assert_eq!(puzzle.cache_path("puzzle.html"), ".cache/2024/day-06/puzzle.html");
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(Puzzle::new(2024, 1).unlock_time(), 1733029200);
+        assert_eq!(Puzzle::new(2024, 2).unlock_time(), 1733115600);
+        assert_eq!(Puzzle::new(2025, 1).unlock_time(), 1764565200);
+    }
+}
@@ -0,0 +1,260 @@
+// sorted sets of half-open ranges and maps from ranges to values, both keyed by range start in a
+// btree so inserts, removes and point lookups stay logarithmic however fragmented things get,
+// searching by length (RangeSet::first_fit) is not indexed and walks the ranges in order
+
+use std::collections::BTreeMap;
+use std::ops::{Add, Range, Sub};
+
+// ranges never overlap or touch, inserting next to one extends it instead
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RangeSet<T> {
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> RangeSet<T> {
+        return RangeSet {
+            ranges: BTreeMap::new(),
+        };
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        let (mut start, mut end) = (range.start, range.end);
+
+        if let Some((&before_start, &before_end)) = self.ranges.range(..=start).next_back() {
+            if before_end >= start {
+                start = before_start;
+                end = end.max(before_end);
+            }
+        }
+
+        let covered: Vec<T> = self.ranges.range(start..=end).map(|(start, _)| *start).collect();
+        for covered_start in covered {
+            if let Some(covered_end) = self.ranges.remove(&covered_start) {
+                end = end.max(covered_end);
+            }
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    // ranges that only partly overlap are cut down to what is left outside of it
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        let mut overlapping: Vec<(T, T)> = Vec::new();
+
+        if let Some((&start, &end)) = self.ranges.range(..range.start).next_back() {
+            if end > range.start {
+                overlapping.push((start, end));
+            }
+        }
+        overlapping.extend(
+            self.ranges
+                .range(range.start..range.end)
+                .map(|(start, end)| (*start, *end)),
+        );
+
+        for (start, end) in overlapping {
+            self.ranges.remove(&start);
+
+            if start < range.start {
+                self.ranges.insert(start, range.start);
+            }
+            if end > range.end {
+                self.ranges.insert(range.end, end);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        return match self.ranges.range(..=value).next_back() {
+            Some((_, &end)) => end > value,
+            None => false,
+        };
+    }
+
+    // the start of the first range with room for length values that all come before `before`,
+    // linear in the number of ranges ahead of it
+    pub fn first_fit(&self, length: T, before: T) -> Option<T> {
+        for (&start, &end) in &self.ranges {
+            if start + length > before {
+                return None;
+            }
+
+            if end - start >= length {
+                return Some(start);
+            }
+        }
+
+        return None;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        return self.ranges.iter().map(|(&start, &end)| start..end);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.ranges.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+}
+
+// a later insert overwrites whatever it overlaps, touching ranges with equal values are merged
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IntervalMap<K, V> {
+    intervals: BTreeMap<K, (K, V)>,
+}
+
+impl<K, V> IntervalMap<K, V>
+where
+    K: Copy + Ord,
+    V: Clone + PartialEq,
+{
+    pub fn new() -> IntervalMap<K, V> {
+        return IntervalMap {
+            intervals: BTreeMap::new(),
+        };
+    }
+
+    pub fn insert(&mut self, range: Range<K>, value: V) {
+        if range.start >= range.end {
+            return;
+        }
+
+        self.remove(range.clone());
+        let (mut start, mut end) = (range.start, range.end);
+
+        let before = self
+            .intervals
+            .range(..start)
+            .next_back()
+            .map(|(start, (end, value))| (*start, *end, value));
+        if let Some((before_start, before_end, before_value)) = before {
+            if before_end == start && *before_value == value {
+                self.intervals.remove(&before_start);
+                start = before_start;
+            }
+        }
+
+        if let Some((after_end, after_value)) = self.intervals.get(&end) {
+            if *after_value == value {
+                let after_start = end;
+                end = *after_end;
+                self.intervals.remove(&after_start);
+            }
+        }
+
+        self.intervals.insert(start, (end, value));
+    }
+
+    pub fn remove(&mut self, range: Range<K>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        let mut overlapping: Vec<K> = Vec::new();
+
+        if let Some((&start, (end, _))) = self.intervals.range(..range.start).next_back() {
+            if *end > range.start {
+                overlapping.push(start);
+            }
+        }
+        overlapping.extend(self.intervals.range(range.start..range.end).map(|(start, _)| *start));
+
+        for start in overlapping {
+            if let Some((end, value)) = self.intervals.remove(&start) {
+                if start < range.start {
+                    self.intervals.insert(start, (range.start, value.clone()));
+                }
+                if end > range.end {
+                    self.intervals.insert(range.end, (end, value));
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, key: K) -> Option<&V> {
+        return match self.intervals.range(..=key).next_back() {
+            Some((_, (end, value))) if *end > key => Some(value),
+            _ => None,
+        };
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        return self.intervals.iter().map(|(&start, (end, value))| (start..*end, value));
+    }
+
+    pub fn len(&self) -> usize {
+        return self.intervals.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_set() {
+        let mut set: RangeSet<usize> = RangeSet::new();
+
+        set.insert(2..4);
+        set.insert(8..10);
+        set.insert(4..5);
+        set.insert(12..18);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![2..5, 8..10, 12..18]);
+
+        set.insert(9..13);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![2..5, 8..18]);
+
+        set.remove(3..4);
+        set.remove(10..12);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![2..3, 4..5, 8..10, 12..18]);
+        assert!(set.contains(9));
+        assert!(!set.contains(10));
+
+        assert_eq!(set.first_fit(2, 20), Some(8));
+        assert_eq!(set.first_fit(3, 20), Some(12));
+        assert_eq!(set.first_fit(3, 14), None);
+        assert_eq!(set.first_fit(1, 2), None);
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    fn test_interval_map() {
+        let mut map: IntervalMap<i64, char> = IntervalMap::new();
+
+        map.insert(0..10, 'a');
+        map.insert(3..5, 'b');
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(0..3, &'a'), (3..5, &'b'), (5..10, &'a')]
+        );
+        assert_eq!(map.get(4), Some(&'b'));
+        assert_eq!(map.get(10), None);
+
+        map.insert(3..5, 'a');
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(0..10, &'a')]);
+
+        map.remove(-5..2);
+        map.insert(12..15, 'a');
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(2..10, &'a'), (12..15, &'a')]);
+        assert_eq!(map.len(), 2);
+    }
+}
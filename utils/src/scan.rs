@@ -0,0 +1,240 @@
+// pulls typed fields out of a line, either by a template where every {} is a field
+// (scan!(line, "p={},{} v={},{}", i32, i32, i32, i32)) or by taking every signed integer in it
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanError {
+    // the text around the fields did not match the template
+    Mismatch {
+        line: String,
+        template: String,
+    },
+    // a field was found but would not parse as its type
+    Field {
+        line: String,
+        field: String,
+        index: usize,
+    },
+    // the line had a different number of fields or integers than asked for
+    Count {
+        line: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            ScanError::Mismatch { line, template } => write!(f, "\"{}\" does not match \"{}\"", line, template),
+            ScanError::Field { line, field, index } => {
+                write!(f, "field {} (\"{}\") of \"{}\" does not parse", index + 1, field, line)
+            }
+            ScanError::Count { line, expected, found } => {
+                write!(f, "expected {} fields in \"{}\" but found {}", expected, line, found)
+            }
+        };
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+// the raw text of every {} in the template, a field runs until the text that follows it
+pub fn scan_fields<'a>(line: &'a str, template: &str) -> Result<Vec<&'a str>, ScanError> {
+    let mismatch = || ScanError::Mismatch {
+        line: line.to_string(),
+        template: template.to_string(),
+    };
+
+    let pieces: Vec<&str> = template.split("{}").collect();
+    let mut rest = line.strip_prefix(pieces[0]).ok_or_else(mismatch)?;
+    let mut fields: Vec<&str> = Vec::new();
+
+    for (i, piece) in pieces.iter().enumerate().skip(1) {
+        let end = match (piece.is_empty(), i == pieces.len() - 1) {
+            (true, true) => rest.len(),
+            // two fields next to each other have nothing to split on
+            (true, false) => return Err(mismatch()),
+            (false, _) => rest.find(piece).ok_or_else(mismatch)?,
+        };
+
+        fields.push(&rest[..end]);
+        rest = &rest[end + piece.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(mismatch());
+    }
+
+    return Ok(fields);
+}
+
+pub fn parse_field<T: FromStr>(line: &str, fields: &[&str], index: usize) -> Result<T, ScanError> {
+    let field = fields.get(index).copied().unwrap_or("");
+
+    return field.trim().parse::<T>().map_err(|_| ScanError::Field {
+        line: line.to_string(),
+        field: field.to_string(),
+        index,
+    });
+}
+
+// implemented for tuples of up to 8 FromStr types, scan!() picks the tuple from its type list
+pub trait FromFields: Sized {
+    const COUNT: usize;
+
+    fn from_fields(line: &str, fields: &[&str]) -> Result<Self, ScanError>;
+}
+
+macro_rules! from_fields {
+    ($count:expr; $($t:ident $index:tt),+) => {
+        impl<$($t: FromStr),+> FromFields for ($($t,)+) {
+            const COUNT: usize = $count;
+
+            fn from_fields(line: &str, fields: &[&str]) -> Result<Self, ScanError> {
+                return Ok(($(parse_field::<$t>(line, fields, $index)?,)+));
+            }
+        }
+    };
+}
+
+from_fields!(1; A 0);
+from_fields!(2; A 0, B 1);
+from_fields!(3; A 0, B 1, C 2);
+from_fields!(4; A 0, B 1, C 2, D 3);
+from_fields!(5; A 0, B 1, C 2, D 3, E 4);
+from_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+from_fields!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+from_fields!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+pub fn scan<T: FromFields>(line: &str, template: &str) -> Result<T, ScanError> {
+    let fields = scan_fields(line, template)?;
+
+    if fields.len() != T::COUNT {
+        return Err(ScanError::Count {
+            line: line.to_string(),
+            expected: T::COUNT,
+            found: fields.len(),
+        });
+    }
+
+    return T::from_fields(line, &fields);
+}
+
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:expr, $($t:ty),+) => {
+        $crate::scan::scan::<($($t,)+)>(&$line, $template)
+    };
+}
+
+// a - right before a digit is a sign unless it follows another digit, so "v=3,-3" is [3, -3]
+// but "2024-12" is [2024, 12]
+pub fn integers<T: FromStr>(line: &str) -> Result<Vec<T>, ScanError> {
+    let bytes = line.as_bytes();
+    let mut found: Vec<T> = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if !signed && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let number = &line[start..i];
+        let value = number.parse::<T>().map_err(|_| ScanError::Field {
+            line: line.to_string(),
+            field: number.to_string(),
+            index: found.len(),
+        })?;
+
+        found.push(value);
+    }
+
+    return Ok(found);
+}
+
+pub fn integer_array<T: FromStr, const N: usize>(line: &str) -> Result<[T; N], ScanError> {
+    let found = integers::<T>(line)?;
+    let count = found.len();
+
+    return found.try_into().map_err(|_| ScanError::Count {
+        line: line.to_string(),
+        expected: N,
+        found: count,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan!("p=0,4 v=3,-3", "p={},{} v={},{}", i32, i32, i32, i32),
+            Ok((0, 4, 3, -3))
+        );
+        assert_eq!(
+            scan!("Button A: X+94, Y+34", "Button {}: X+{}, Y+{}", char, u64, u64),
+            Ok(('A', 94, 34))
+        );
+        assert_eq!(
+            scan!("190: 10 19", "{}: {}", u64, String),
+            Ok((190, "10 19".to_string()))
+        );
+
+        assert_eq!(
+            scan!("p=0,4 v=3,-3", "p={},{} w={},{}", i32, i32, i32, i32),
+            Err(ScanError::Mismatch {
+                line: "p=0,4 v=3,-3".to_string(),
+                template: "p={},{} w={},{}".to_string()
+            })
+        );
+        assert_eq!(
+            scan!("p=0,x", "p={},{}", i32, i32),
+            Err(ScanError::Field {
+                line: "p=0,x".to_string(),
+                field: "x".to_string(),
+                index: 1
+            })
+        );
+        assert!(matches!(
+            scan!("p=0,4", "p={},{}", i32),
+            Err(ScanError::Count {
+                expected: 1,
+                found: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers::<i64>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(integers::<i64>("2024-12 to -5"), Ok(vec![2024, 12, -5]));
+        assert_eq!(integers::<i64>("none here"), Ok(vec![]));
+        assert!(integers::<u8>("300").is_err());
+        assert_eq!(integer_array::<u64, 2>("Prize: X=8400, Y=5400"), Ok([8400, 5400]));
+        assert!(matches!(
+            integer_array::<u64, 3>("Prize: X=8400, Y=5400"),
+            Err(ScanError::Count {
+                expected: 3,
+                found: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,311 @@
+// step by step simulations that can be watched in the terminal (cargo run --features animate --
+// --animate) or dumped frame by frame as text for tests, the player keeps a window of past frames
+// so it can scrub backwards through a simulation that only ever runs forwards
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellKind {
+    Empty,
+    Wall,
+    Visited,
+    Item,
+    Actor,
+    Highlight,
+    // anything else a day wants to tell apart, a gif takes its colour from the renderer's palette
+    // and the terminal from the 256 colour table
+    Shade(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub kind: CellKind,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, kind: CellKind) -> Cell {
+        return Cell { glyph, kind };
+    }
+}
+
+pub type Frame = Vec<Vec<Cell>>;
+
+pub trait Simulation {
+    fn step(&mut self);
+    fn is_done(&self) -> bool;
+    fn render(&self) -> Frame;
+
+    // shown under the frame, something like the step count or the move being made
+    fn caption(&self) -> String {
+        return String::new();
+    }
+}
+
+pub fn requested() -> bool {
+    return std::env::args().any(|arg| arg == "--animate");
+}
+
+pub fn frame_text(frame: &Frame) -> String {
+    return frame
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.glyph).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+// the starting frame and one more per step, stops after limit steps if it never finishes
+pub fn headless<S: Simulation>(simulation: &mut S, limit: usize) -> Vec<String> {
+    let mut frames: Vec<String> = vec![frame_text(&simulation.render())];
+
+    while !simulation.is_done() && frames.len() <= limit {
+        simulation.step();
+        frames.push(frame_text(&simulation.render()));
+    }
+
+    return frames;
+}
+
+#[cfg(feature = "animate")]
+pub use player::play;
+
+#[cfg(not(feature = "animate"))]
+pub fn play<S: Simulation>(_simulation: &mut S) -> Result<(), Box<dyn std::error::Error>> {
+    eprintln!("built without the animate feature, rerun with --features animate");
+    return Ok(());
+}
+
+#[cfg(feature = "animate")]
+mod player {
+    use std::collections::VecDeque;
+    use std::error::Error;
+    use std::io::{Stdout, Write};
+    use std::time::Duration;
+
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+    use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+    use crossterm::{cursor, execute, queue, terminal};
+
+    use super::{CellKind, Frame, Simulation};
+
+    // a frame of the biggest grids is a few hundred kb, so only the most recent ones are kept
+    const HISTORY: usize = 500;
+    const HELP: &str = "space play/pause  . , step  left/right scrub  pgup/pgdn scrub 50  + - speed  q quit";
+
+    struct Player {
+        frames: VecDeque<(Frame, String)>,
+        // the step number of frames[0]
+        first: usize,
+        current: usize,
+        playing: bool,
+        delay: Duration,
+    }
+
+    impl Player {
+        fn last(&self) -> usize {
+            return self.first + self.frames.len() - 1;
+        }
+
+        fn forward<S: Simulation>(&mut self, simulation: &mut S) -> bool {
+            if self.current < self.last() {
+                self.current += 1;
+                return true;
+            }
+
+            if simulation.is_done() {
+                return false;
+            }
+
+            simulation.step();
+            self.frames.push_back((simulation.render(), simulation.caption()));
+            if self.frames.len() > HISTORY {
+                self.frames.pop_front();
+                self.first += 1;
+            }
+
+            self.current = self.last();
+            return true;
+        }
+
+        fn back(&mut self, steps: usize) {
+            self.current = self.current.saturating_sub(steps).max(self.first);
+        }
+    }
+
+    fn colour(kind: CellKind) -> Color {
+        return match kind {
+            CellKind::Empty => Color::DarkGrey,
+            CellKind::Wall => Color::Grey,
+            CellKind::Visited => Color::Blue,
+            CellKind::Item => Color::Yellow,
+            CellKind::Actor => Color::Red,
+            CellKind::Highlight => Color::Green,
+            CellKind::Shade(shade) => Color::AnsiValue(shade),
+        };
+    }
+
+    fn draw(stdout: &mut Stdout, player: &Player) -> Result<(), Box<dyn Error>> {
+        let (width, height) = terminal::size()?;
+        let (frame, caption) = &player.frames[player.current - player.first];
+        let rows = frame.len().min((height as usize).saturating_sub(2));
+
+        for (i, row) in frame.iter().take(rows).enumerate() {
+            queue!(stdout, cursor::MoveTo(0, i as u16))?;
+
+            // one colour change per run of the same kind rather than per cell
+            let mut kind: Option<CellKind> = None;
+            for cell in row.iter().take(width as usize) {
+                if kind != Some(cell.kind) {
+                    kind = Some(cell.kind);
+                    queue!(stdout, SetForegroundColor(colour(cell.kind)))?;
+                }
+                queue!(stdout, Print(cell.glyph))?;
+            }
+        }
+
+        let status = format!(
+            "step {}{}  {}ms  {}  {}",
+            player.current,
+            if player.playing { "" } else { " (paused)" },
+            player.delay.as_millis(),
+            caption,
+            HELP
+        );
+
+        queue!(
+            stdout,
+            ResetColor,
+            cursor::MoveTo(0, rows as u16 + 1),
+            terminal::Clear(terminal::ClearType::CurrentLine),
+            Print(status.chars().take(width as usize).collect::<String>())
+        )?;
+        stdout.flush()?;
+
+        return Ok(());
+    }
+
+    fn run<S: Simulation>(stdout: &mut Stdout, simulation: &mut S) -> Result<(), Box<dyn Error>> {
+        let mut player = Player {
+            frames: VecDeque::from([(simulation.render(), simulation.caption())]),
+            first: 0,
+            current: 0,
+            playing: false,
+            delay: Duration::from_millis(100),
+        };
+
+        loop {
+            draw(stdout, &player)?;
+
+            let timeout = if player.playing {
+                player.delay
+            } else {
+                Duration::from_secs(60)
+            };
+
+            if !event::poll(timeout)? {
+                if player.playing && !player.forward(simulation) {
+                    player.playing = false;
+                }
+                continue;
+            }
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                Event::Resize(_, _) => {
+                    execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+                    continue;
+                }
+                _ => continue,
+            };
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char(' ') => player.playing = !player.playing,
+                KeyCode::Char('.') | KeyCode::Right => {
+                    player.playing = false;
+                    player.forward(simulation);
+                }
+                KeyCode::Char(',') | KeyCode::Left => {
+                    player.playing = false;
+                    player.back(1);
+                }
+                KeyCode::PageDown => {
+                    player.playing = false;
+                    for _ in 0..50 {
+                        player.forward(simulation);
+                    }
+                }
+                KeyCode::PageUp => {
+                    player.playing = false;
+                    player.back(50);
+                }
+                KeyCode::Home => player.current = player.first,
+                KeyCode::End => player.current = player.last(),
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    player.delay = (player.delay / 2).max(Duration::from_millis(1));
+                }
+                KeyCode::Char('-') => player.delay = (player.delay * 2).min(Duration::from_secs(2)),
+                _ => {}
+            }
+        }
+    }
+
+    // space plays and pauses, . and , step one frame, the arrows and page keys scrub through what
+    // has been seen so far, + and - halve and double the delay
+    pub fn play<S: Simulation>(simulation: &mut S) -> Result<(), Box<dyn Error>> {
+        let mut stdout = std::io::stdout();
+
+        terminal::enable_raw_mode()?;
+        execute!(
+            stdout,
+            terminal::EnterAlternateScreen,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::Hide
+        )?;
+
+        let result = run(&mut stdout, simulation);
+
+        // put the terminal back even when drawing failed
+        let _ = execute!(stdout, ResetColor, cursor::Show, terminal::LeaveAlternateScreen);
+        terminal::disable_raw_mode()?;
+
+        return result;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a dot walking right until it hits the wall
+    struct Walker {
+        position: usize,
+        width: usize,
+    }
+
+    impl Simulation for Walker {
+        fn step(&mut self) {
+            self.position += 1;
+        }
+
+        fn is_done(&self) -> bool {
+            return self.position + 1 == self.width;
+        }
+
+        fn render(&self) -> Frame {
+            let mut row = vec![Cell::new('.', CellKind::Empty); self.width];
+            row[self.position] = Cell::new('@', CellKind::Actor);
+            return vec![row];
+        }
+    }
+
+    #[test]
+    fn test_headless() {
+        assert_eq!(
+            headless(&mut Walker { position: 0, width: 3 }, 10),
+            vec!["@..", ".@.", "..@"]
+        );
+        assert_eq!(
+            headless(&mut Walker { position: 0, width: 10 }, 2),
+            vec!["@.........", ".@........", "..@......."]
+        );
+    }
+}
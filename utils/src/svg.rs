@@ -0,0 +1,233 @@
+// standalone svg drawings over a grid, cells are addressed with geom points (x is the column, y the
+// row) and everything is drawn in the order it was added, so fills should go in before lines
+
+use std::error::Error;
+use std::fmt::Write;
+
+use crate::geom::Point;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    rows: usize,
+    cols: usize,
+    cell_size: u32,
+    elements: Vec<String>,
+}
+
+// spreads colours around the wheel by the golden angle so neighbouring indexes never look alike
+pub fn hue(index: usize) -> String {
+    return format!("hsl({}, 65%, 60%)", (index * 137) % 360);
+}
+
+pub fn requested() -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let position = args.iter().position(|arg| arg == "--svg")?;
+
+    return args.get(position + 1).cloned();
+}
+
+// --svg <file> for a day, the drawing is only made when it was asked for, false when writing failed
+// and the day should stop
+pub fn save_requested(draw: impl FnOnce() -> Svg) -> bool {
+    let path = match requested() {
+        Some(path) => path,
+        None => return true,
+    };
+
+    if let Err(e) = draw().save(&path) {
+        println!("Error: Failed to write {}. {}", path, e);
+        return false;
+    }
+    eprintln!("wrote {}", path);
+
+    return true;
+}
+
+fn escape(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
+
+impl Svg {
+    pub fn new(rows: usize, cols: usize, cell_size: u32) -> Svg {
+        return Svg {
+            rows,
+            cols,
+            cell_size,
+            elements: Vec::new(),
+        };
+    }
+
+    fn centre(&self, cell: Point) -> (i64, i64) {
+        let size = self.cell_size as i64;
+        return (cell.x * size + size / 2, cell.y * size + size / 2);
+    }
+
+    pub fn fill(&mut self, cell: Point, colour: &str) -> &mut Svg {
+        let size = self.cell_size as i64;
+        self.elements.push(format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            cell.x * size,
+            cell.y * size,
+            size,
+            size,
+            escape(colour)
+        ));
+
+        return self;
+    }
+
+    // thin lines around every cell
+    pub fn grid(&mut self, colour: &str) -> &mut Svg {
+        let size = self.cell_size as usize;
+        let (width, height) = (self.cols * size, self.rows * size);
+        let mut path = String::new();
+
+        for row in 0..=self.rows {
+            let _ = write!(path, "M0 {}H{}", row * size, width);
+        }
+        for col in 0..=self.cols {
+            let _ = write!(path, "M{} 0V{}", col * size, height);
+        }
+
+        self.elements.push(format!(
+            "<path d=\"{}\" stroke=\"{}\" stroke-width=\"0.5\" fill=\"none\"/>",
+            path,
+            escape(colour)
+        ));
+
+        return self;
+    }
+
+    // through the centres of the cells in order
+    pub fn polyline(&mut self, cells: &[Point], colour: &str, width: f64) -> &mut Svg {
+        if cells.len() < 2 {
+            return self;
+        }
+
+        let points: Vec<String> = cells
+            .iter()
+            .map(|cell| {
+                let (x, y) = self.centre(*cell);
+                format!("{},{}", x, y)
+            })
+            .collect();
+
+        self.elements.push(format!(
+            "<polyline points=\"{}\" stroke=\"{}\" stroke-width=\"{}\" fill=\"none\" stroke-linecap=\"round\" \
+             stroke-linejoin=\"round\"/>",
+            points.join(" "),
+            escape(colour),
+            width
+        ));
+
+        return self;
+    }
+
+    // the side two neighbouring cells share, a fence between them, cells that do not touch are
+    // skipped
+    pub fn edge(&mut self, a: Point, b: Point, colour: &str, width: f64) -> &mut Svg {
+        if a.manhattan(b) != 1 {
+            return self;
+        }
+
+        let size = self.cell_size as i64;
+        let (x, y) = (a.x.max(b.x) * size, a.y.max(b.y) * size);
+        let (x2, y2) = if a.x != b.x { (x, y + size) } else { (x + size, y) };
+
+        self.elements.push(format!(
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"square\"/>",
+            x,
+            y,
+            x2,
+            y2,
+            escape(colour),
+            width
+        ));
+
+        return self;
+    }
+
+    // centred on the cell
+    pub fn label(&mut self, cell: Point, text: &str, colour: &str) -> &mut Svg {
+        let (x, y) = self.centre(cell);
+
+        self.elements.push(format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"{}\" font-size=\"{}\" font-family=\"monospace\" text-anchor=\"middle\" \
+             dominant-baseline=\"central\">{}</text>",
+            x,
+            y,
+            escape(colour),
+            self.cell_size * 3 / 4,
+            escape(text)
+        ));
+
+        return self;
+    }
+
+    pub fn render(&self) -> String {
+        let (width, height) = (self.cols * self.cell_size as usize, self.rows * self.cell_size as usize);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            width, height, width, height
+        );
+
+        for element in &self.elements {
+            svg.push_str(element);
+            svg.push('\n');
+        }
+        svg.push_str("</svg>\n");
+
+        return svg;
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        std::fs::write(path, self.render())?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut svg = Svg::new(1, 2, 10);
+        svg.fill(Point::new(1, 0), "red")
+            .edge(Point::new(0, 0), Point::new(1, 0), "black", 2.0)
+            .edge(Point::new(0, 0), Point::new(1, 1), "black", 2.0)
+            .polyline(&[Point::new(0, 0), Point::new(1, 0)], "blue", 1.5)
+            .label(Point::new(0, 0), "<A&B>", "#000");
+
+        assert_eq!(
+            svg.render(),
+            [
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"10\" viewBox=\"0 0 20 10\">",
+                "<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"red\"/>",
+                "<line x1=\"10\" y1=\"0\" x2=\"10\" y2=\"10\" stroke=\"black\" stroke-width=\"2\" \
+                 stroke-linecap=\"square\"/>",
+                "<polyline points=\"5,5 15,5\" stroke=\"blue\" stroke-width=\"1.5\" fill=\"none\" \
+                 stroke-linecap=\"round\" stroke-linejoin=\"round\"/>",
+                "<text x=\"5\" y=\"5\" fill=\"#000\" font-size=\"7\" font-family=\"monospace\" text-anchor=\"middle\" \
+                 dominant-baseline=\"central\">&lt;A&amp;B&gt;</text>",
+                "</svg>",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut svg = Svg::new(2, 1, 4);
+        svg.grid("grey").edge(Point::new(0, 0), Point::new(0, 1), "black", 1.0);
+
+        assert!(svg.render().contains("d=\"M0 0H4M0 4H4M0 8H4M0 0V8M4 0V8\""));
+        assert!(svg.render().contains("x1=\"0\" y1=\"4\" x2=\"4\" y2=\"4\""));
+        assert_eq!(hue(1), "hsl(137, 65%, 60%)");
+    }
+}
@@ -0,0 +1,160 @@
+// phase timings and counters for the day crates, everything here compiles away unless the
+// trace or alloc feature is on (cargo run --features trace -- -v)
+
+#[cfg(feature = "trace")]
+pub use tracing;
+
+pub fn verbosity() -> usize {
+    return std::env::args()
+        .map(|arg| match arg.as_str() {
+            "-v" => 1,
+            "-vv" => 2,
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0);
+}
+
+// -v prints parse / part one / part two timings and counters, -vv adds the inner loops
+#[cfg(feature = "trace")]
+pub fn init() {
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    let level = match verbosity() {
+        0 => return,
+        1 => tracing::Level::INFO,
+        _ => tracing::Level::DEBUG,
+    };
+
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false)
+        .without_time()
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+#[cfg(not(feature = "trace"))]
+pub fn init() {
+    if verbosity() > 0 {
+        eprintln!("built without the trace feature, rerun with --features trace");
+    }
+}
+
+// a phase is entered for parse / part one / part two, with the alloc feature it also reports
+// the time and allocations of that phase when it ends
+pub struct Phase {
+    #[cfg(feature = "trace")]
+    _span: tracing::span::EnteredSpan,
+    #[cfg(feature = "alloc")]
+    name: &'static str,
+    #[cfg(feature = "alloc")]
+    start: std::time::Instant,
+    #[cfg(feature = "alloc")]
+    before: crate::alloc::Snapshot,
+}
+
+impl Phase {
+    #[inline(always)]
+    pub fn start(_name: &'static str) -> Phase {
+        #[cfg(feature = "alloc")]
+        crate::alloc::reset_peak();
+
+        return Phase {
+            #[cfg(feature = "trace")]
+            _span: tracing::info_span!("phase", name = _name).entered(),
+            #[cfg(feature = "alloc")]
+            name: _name,
+            #[cfg(feature = "alloc")]
+            start: std::time::Instant::now(),
+            #[cfg(feature = "alloc")]
+            before: crate::alloc::snapshot(),
+        };
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Phase {
+    fn drop(&mut self) {
+        let after = crate::alloc::snapshot();
+
+        // the heap peak is this phase's alone, the resident one is the whole process's so far
+        let resident = crate::alloc::peak_resident().map_or("unknown".to_string(), crate::alloc::format_bytes);
+
+        eprintln!(
+            "{}: {:?}, {} allocations, {} allocated, {} peak heap, {} peak resident",
+            self.name,
+            self.start.elapsed(),
+            after.allocations - self.before.allocations,
+            crate::alloc::format_bytes(after.allocated - self.before.allocated),
+            crate::alloc::format_bytes(crate::alloc::peak().saturating_sub(self.before.in_use)),
+            resident
+        );
+    }
+}
+
+#[macro_export]
+macro_rules! phase {
+    ($name:expr, $body:expr) => {{
+        let _phase = $crate::trace::Phase::start($name);
+        $body
+    }};
+}
+
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! step {
+    ($name:expr, $body:expr) => {{
+        let _span = $crate::trace::tracing::debug_span!($name).entered();
+        $body
+    }};
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! step {
+    ($name:expr, $body:expr) => {
+        $body
+    };
+}
+
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! counter {
+    ($name:expr, $value:expr) => {
+        $crate::trace::tracing::info!("{}: {}", $name, $value)
+    };
+}
+
+// the value goes in a closure that is never called so it still type checks and counts as a use of
+// whatever it borrows, but costs nothing and can't panic
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! counter {
+    ($name:expr, $value:expr) => {
+        let _ = || {
+            let _ = &$value;
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_phase() {
+        let result = crate::phase!("part one", crate::step!("inner", 20 + 1) * 2);
+        crate::counter!("result", result);
+
+        assert_eq!(result, 42);
+    }
+
+    #[cfg(not(feature = "trace"))]
+    #[test]
+    fn test_counter_not_evaluated() {
+        let regions: Vec<u32> = Vec::new();
+        crate::counter!("regions", regions.len() - 1);
+
+        assert!(regions.is_empty());
+    }
+}
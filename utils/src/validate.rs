@@ -0,0 +1,300 @@
+// structural checks on a day's raw input before its loader sees it, the loaders assume a shape
+// (rectangular maps, one guard, whole machine blocks) and quietly give wrong answers or panic when
+// the input is off, every day has a validate function built from these checks
+// (cargo run -p aoc -- 2024 validate 6)
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub file: String,
+    // counted from 1 like an editor, None when it is about the whole file
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file, line, self.message),
+            None => write!(f, "{}: {}", self.file, self.message),
+        };
+    }
+}
+
+// the non-blank lines of a file with their line numbers, blank lines are skipped like get_lines and
+// the csv reader do but the numbers still point at the right line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    pub file: String,
+    pub lines: Vec<(usize, String)>,
+}
+
+impl Input {
+    pub fn read(path: &str) -> Result<Input, Box<dyn Error>> {
+        return Ok(Input::new(path, &std::fs::read_to_string(path)?));
+    }
+
+    pub fn new(file: &str, text: &str) -> Input {
+        return Input {
+            file: file.to_string(),
+            lines: text
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| (i + 1, line.to_string()))
+                .collect(),
+        };
+    }
+
+    fn problem(&self, line: Option<usize>, message: String) -> Problem {
+        return Problem {
+            file: self.file.clone(),
+            line,
+            message,
+        };
+    }
+
+    pub fn not_empty(&self) -> Vec<Problem> {
+        if self.lines.is_empty() {
+            return vec![self.problem(None, "is empty".to_string())];
+        }
+
+        return Vec::new();
+    }
+
+    pub fn single_line(&self) -> Vec<Problem> {
+        let mut problems = self.not_empty();
+
+        if let Some((line, _)) = self.lines.get(1) {
+            problems.push(self.problem(Some(*line), format!("expected one line but found {}", self.lines.len())));
+        }
+
+        return problems;
+    }
+
+    // every row as wide as the first
+    pub fn rectangular(&self) -> Vec<Problem> {
+        let mut problems = self.not_empty();
+        let width = self.lines.first().map_or(0, |(_, row)| row.chars().count());
+
+        for (line, row) in &self.lines {
+            let length = row.chars().count();
+            if length != width {
+                problems.push(self.problem(
+                    Some(*line),
+                    format!("row is {} wide but the first is {}", length, width),
+                ));
+            }
+        }
+
+        return problems;
+    }
+
+    // the first cell on each line that is not allowed
+    pub fn only(&self, allowed: impl Fn(char) -> bool) -> Vec<Problem> {
+        let mut problems = self.not_empty();
+
+        for (line, row) in &self.lines {
+            if let Some((column, cell)) = row.chars().enumerate().find(|(_, cell)| !allowed(*cell)) {
+                problems.push(self.problem(Some(*line), format!("unexpected {:?} in column {}", cell, column + 1)));
+            }
+        }
+
+        return problems;
+    }
+
+    // a marker like the guard or the robot that the loader expects to find exactly so many times
+    pub fn exactly(&self, marker: char, count: usize) -> Vec<Problem> {
+        let found: Vec<usize> = self
+            .lines
+            .iter()
+            .flat_map(|(line, row)| row.chars().filter(|cell| *cell == marker).map(move |_| *line))
+            .collect();
+
+        if found.len() != count {
+            return vec![self.problem(
+                found.get(count).copied(),
+                format!("expected {} {:?} but found {}", count, marker, found.len()),
+            )];
+        }
+
+        return Vec::new();
+    }
+
+    // every line through a parser, scan! or integers usually, its error is the problem
+    pub fn each<T, E: fmt::Display>(&self, check: impl Fn(&str) -> Result<T, E>) -> Vec<Problem> {
+        let mut problems = self.not_empty();
+
+        for (line, row) in &self.lines {
+            if let Err(e) = check(row) {
+                problems.push(self.problem(Some(*line), e.to_string()));
+            }
+        }
+
+        return problems;
+    }
+
+    // records that span several lines, each block is joined with newlines before it is checked
+    pub fn blocks<T, E: fmt::Display>(&self, size: usize, check: impl Fn(&str) -> Result<T, E>) -> Vec<Problem> {
+        let mut problems = self.not_empty();
+        let size = size.max(1);
+
+        if !self.lines.len().is_multiple_of(size) {
+            problems.push(self.problem(
+                self.lines.get(self.lines.len() / size * size).map(|(line, _)| *line),
+                format!(
+                    "{} lines is not a whole number of {} line blocks",
+                    self.lines.len(),
+                    size
+                ),
+            ));
+        }
+
+        for block in self.lines.chunks_exact(size) {
+            let text: Vec<&str> = block.iter().map(|(_, row)| row.as_str()).collect();
+            if let Err(e) = check(&text.join("\n")) {
+                problems.push(self.problem(Some(block[0].0), e.to_string()));
+            }
+        }
+
+        return problems;
+    }
+}
+
+// the comma separated numbers the csv reader hands the loader, or the first column that will not parse
+pub fn columns<T: FromStr>(line: &str) -> Result<Vec<T>, String> {
+    return line
+        .split(',')
+        .enumerate()
+        .map(|(i, column)| {
+            column
+                .parse::<T>()
+                .map_err(|_| format!("column {} ({:?}) is not a number", i + 1, column))
+        })
+        .collect();
+}
+
+type Found = Vec<(Option<usize>, String)>;
+
+// every problem a day's validate finds in a broken input as (line, message), validate is handed the
+// input written to a scratch directory the way read_back hands it to a loader
+pub fn problems_in(
+    name: &str,
+    files: &[(&str, String)],
+    validate: impl FnOnce(&str) -> Result<Vec<Problem>, Box<dyn Error>>,
+) -> Result<Found, Box<dyn Error>> {
+    return Ok(crate::generate::read_back(name, files, validate)?
+        .into_iter()
+        .map(|problem| (problem.line, problem.message))
+        .collect());
+}
+
+// --validate on the command line, the day reports on its input instead of solving it
+pub fn requested() -> bool {
+    return std::env::args().any(|arg| arg == "--validate");
+}
+
+// days run this before solving, true when it is safe to carry on, --validate prints every problem
+// and stops, otherwise the first problem stops the day as an error
+pub fn check(problems: Result<Vec<Problem>, Box<dyn Error>>) -> bool {
+    let problems = match problems {
+        Ok(problems) => problems,
+        Err(e) => {
+            println!("Error: Failed to validate input. {}", e);
+            return false;
+        }
+    };
+
+    if requested() {
+        for problem in &problems {
+            println!("{}", problem);
+        }
+        if problems.is_empty() {
+            println!("no problems found");
+        }
+        return false;
+    }
+
+    if let Some(problem) = problems.first() {
+        println!("Error: Failed to validate input. {}", problem);
+        return false;
+    }
+
+    return true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_checks() {
+        let input = Input::new("map.csv", "..#.\n\n.^..\n.#.\n^...\n");
+
+        assert_eq!(input.lines[1], (3, ".^..".to_string()));
+        assert_eq!(
+            input
+                .rectangular()
+                .iter()
+                .map(|problem| problem.to_string())
+                .collect::<Vec<String>>(),
+            vec!["map.csv:4: row is 3 wide but the first is 4"]
+        );
+        assert_eq!(
+            input.exactly('^', 1)[0].to_string(),
+            "map.csv:5: expected 1 '^' but found 2"
+        );
+        assert_eq!(input.exactly('@', 1)[0].line, None);
+        assert!(input.only(|cell| ".#^".contains(cell)).is_empty());
+        assert_eq!(input.only(|cell| cell != '#')[0].message, "unexpected '#' in column 3");
+        assert_eq!(input.single_line()[0].line, Some(3));
+        assert_eq!(
+            Input::new("empty.csv", "\n").rectangular()[0].to_string(),
+            "empty.csv: is empty"
+        );
+    }
+
+    #[test]
+    fn test_line_checks() {
+        let input = Input::new("input.csv", "1,2\n3,x\n5,6\n");
+        let problems = input.each(|line| line.split(',').map(str::parse::<u32>).collect::<Result<Vec<u32>, _>>());
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, Some(2));
+        assert_eq!(
+            columns::<u32>("1, 2"),
+            Err("column 2 (\" 2\") is not a number".to_string())
+        );
+        assert_eq!(
+            input.each(columns::<u32>)[0].message,
+            "column 2 (\"x\") is not a number"
+        );
+
+        let problems = input.blocks(2, |block| match block.lines().count() {
+            2 => Ok(()),
+            _ => Err("short block"),
+        });
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| problem.to_string())
+                .collect::<Vec<String>>(),
+            vec!["input.csv:3: 3 lines is not a whole number of 2 line blocks"]
+        );
+    }
+
+    #[test]
+    fn test_problems_in() {
+        let found = problems_in("utils-validate", &[("input.csv", "1,2\n3,x\n".to_string())], |path| {
+            Ok(Input::read(path)?.each(columns::<u32>))
+        });
+
+        assert_eq!(
+            found.ok(),
+            Some(vec![(Some(2), "column 2 (\"x\") is not a number".to_string())])
+        );
+    }
+}
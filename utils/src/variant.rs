@@ -0,0 +1,267 @@
+// several ways of solving the same part kept side by side instead of commented out, a day registers
+// them by part and name and --impl all runs every one, checks they agree and times them
+// (cargo run -p aoc -- 2024 13 --impl all), --impl <name> runs just the ones with that name, an
+// experiment is an attempt that is not right yet, it runs and is timed with the rest but its answer
+// is only shown next to its timing and never checked against the others
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+type Solve<'a> = Box<dyn Fn() -> String + 'a>;
+
+struct Variant<'a> {
+    part: u32,
+    name: &'static str,
+    experimental: bool,
+    solve: Solve<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub part: u32,
+    pub name: &'static str,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub experimental: bool,
+}
+
+#[derive(Default)]
+pub struct Registry<'a> {
+    variants: Vec<Variant<'a>>,
+}
+
+impl<'a> Registry<'a> {
+    pub fn new() -> Registry<'a> {
+        return Registry { variants: Vec::new() };
+    }
+
+    pub fn add<T: Display>(self, part: u32, name: &'static str, solve: impl Fn() -> T + 'a) -> Registry<'a> {
+        return self.push(part, name, false, solve);
+    }
+
+    pub fn experiment<T: Display>(self, part: u32, name: &'static str, solve: impl Fn() -> T + 'a) -> Registry<'a> {
+        return self.push(part, name, true, solve);
+    }
+
+    fn push<T: Display>(
+        mut self,
+        part: u32,
+        name: &'static str,
+        experimental: bool,
+        solve: impl Fn() -> T + 'a,
+    ) -> Registry<'a> {
+        self.variants.push(Variant {
+            part,
+            name,
+            experimental,
+            solve: Box::new(move || solve().to_string()),
+        });
+
+        return self;
+    }
+
+    // every name once, in the order they were first registered
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = Vec::new();
+
+        for variant in &self.variants {
+            if !names.contains(&variant.name) {
+                names.push(variant.name);
+            }
+        }
+
+        return names;
+    }
+
+    // parts in order and each part's variants in the order they were registered
+    pub fn run(&self, selected: &str) -> Result<Vec<Outcome>, String> {
+        let mut variants: Vec<&Variant> = self
+            .variants
+            .iter()
+            .filter(|variant| selected == "all" || variant.name == selected)
+            .collect();
+
+        if variants.is_empty() {
+            return Err(format!(
+                "no implementation called {}, try all or one of {}",
+                selected,
+                self.names().join(", ")
+            ));
+        }
+        variants.sort_by_key(|variant| variant.part);
+
+        return Ok(variants
+            .iter()
+            .map(|variant| {
+                let start = Instant::now();
+                let answer = (variant.solve)();
+
+                Outcome {
+                    part: variant.part,
+                    name: variant.name,
+                    answer,
+                    elapsed: start.elapsed(),
+                    experimental: variant.experimental,
+                }
+            })
+            .collect());
+    }
+}
+
+// one answer per part when every variant of it agreed, otherwise the first one that did not,
+// experiments are left out
+pub fn agreed(outcomes: &[Outcome]) -> Result<Vec<(u32, String)>, String> {
+    let checked: Vec<&Outcome> = outcomes.iter().filter(|outcome| !outcome.experimental).collect();
+    let mut answers: Vec<(u32, String)> = Vec::new();
+
+    for (i, outcome) in checked.iter().enumerate() {
+        let first = match checked[..i].iter().find(|other| other.part == outcome.part) {
+            Some(first) => first,
+            None => {
+                answers.push((outcome.part, outcome.answer.clone()));
+                continue;
+            }
+        };
+
+        if first.answer != outcome.answer {
+            return Err(format!(
+                "part {}: {} gave {} but {} gave {}",
+                outcome.part, first.name, first.answer, outcome.name, outcome.answer
+            ));
+        }
+    }
+
+    return Ok(answers);
+}
+
+// one row per variant with how much slower it was than the fastest of its part
+pub fn render_timings(outcomes: &[Outcome]) -> String {
+    let width = outcomes.iter().map(|outcome| outcome.name.len()).max().unwrap_or(0);
+    let mut table = String::new();
+
+    for outcome in outcomes {
+        let fastest = outcomes
+            .iter()
+            .filter(|other| other.part == outcome.part)
+            .map(|other| other.elapsed)
+            .min()
+            .unwrap_or(outcome.elapsed);
+        let ratio = outcome.elapsed.as_secs_f64() / fastest.as_secs_f64().max(f64::EPSILON);
+
+        table.push_str(&format!(
+            "part {} {:<width$}  {:>12?}  {:>8.1}x",
+            outcome.part,
+            outcome.name,
+            outcome.elapsed,
+            ratio,
+            width = width
+        ));
+        if outcome.experimental {
+            table.push_str(&format!("  experiment gave {}", outcome.answer));
+        }
+        table.push('\n');
+    }
+
+    return table;
+}
+
+// --impl <name>, all runs every implementation
+pub fn requested() -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let position = args.iter().position(|arg| arg == "--impl")?;
+
+    return Some(args.get(position + 1).cloned().unwrap_or_else(|| "all".to_string()));
+}
+
+// the timings go to stderr and the answers to stdout as "part N: answer" so aoc still checks them
+// against answers.csv
+pub fn compare(registry: &Registry, selected: &str) -> Result<(), String> {
+    let outcomes = registry.run(selected)?;
+    eprint!("{}", render_timings(&outcomes));
+
+    for (part, answer) in agreed(&outcomes)? {
+        println!("part {}: {}", part, answer);
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let numbers: Vec<u64> = (1..=20).collect();
+        let registry = Registry::new()
+            .add(2, "fast", || numbers.iter().product::<u64>() % 7)
+            .add(1, "fast", || numbers.len() * (numbers.len() + 1) / 2)
+            .add(1, "naive", || numbers.iter().sum::<u64>())
+            .add(2, "naive", || numbers.iter().fold(1, |product, n| product * n % 7));
+
+        assert_eq!(registry.names(), vec!["fast", "naive"]);
+
+        let outcomes = match registry.run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run every implementation. {}", e);
+            }
+        };
+        let ran: Vec<(u32, &str)> = outcomes.iter().map(|outcome| (outcome.part, outcome.name)).collect();
+
+        assert_eq!(ran, vec![(1, "fast"), (1, "naive"), (2, "fast"), (2, "naive")]);
+        assert_eq!(
+            agreed(&outcomes),
+            Ok(vec![(1, "210".to_string()), (2, "0".to_string())])
+        );
+        assert_eq!(render_timings(&outcomes).lines().count(), 4);
+        assert!(render_timings(&outcomes).starts_with("part 1 fast "));
+
+        assert_eq!(registry.run("naive").map(|outcomes| outcomes.len()), Ok(2));
+        assert_eq!(
+            registry.run("parallel").err(),
+            Some("no implementation called parallel, try all or one of fast, naive".to_string())
+        );
+    }
+
+    #[test]
+    fn test_agreed() {
+        let outcome = |name: &'static str, answer: &str| Outcome {
+            part: 1,
+            name,
+            answer: answer.to_string(),
+            elapsed: Duration::ZERO,
+            experimental: false,
+        };
+
+        assert_eq!(
+            agreed(&[outcome("fast", "6"), outcome("naive", "6"), outcome("parallel", "7")]),
+            Err("part 1: fast gave 6 but parallel gave 7".to_string())
+        );
+        assert_eq!(agreed(&[]), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_experiment() {
+        let numbers: Vec<u64> = (1..=20).collect();
+        let registry = Registry::new()
+            .add(1, "fast", || numbers.len() * (numbers.len() + 1) / 2)
+            .experiment(1, "guess", || numbers.len() * numbers.len() / 2)
+            .experiment(2, "guess", || numbers.len());
+
+        let outcomes = match registry.run("all") {
+            Ok(result) => result,
+            Err(e) => {
+                panic!("Error: Failed to run every implementation. {}", e);
+            }
+        };
+
+        // the guess is wrong and the only one for part two but neither holds up the answers
+        assert_eq!(agreed(&outcomes), Ok(vec![(1, "210".to_string())]));
+
+        let table = render_timings(&outcomes);
+        assert!(!table.lines().next().unwrap_or("").contains("experiment"));
+        assert!(table.lines().nth(1).unwrap_or("").ends_with("  experiment gave 200"));
+        assert!(table.lines().nth(2).unwrap_or("").ends_with("  experiment gave 20"));
+    }
+}